@@ -0,0 +1,61 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![feature(test)]
+
+extern crate test;
+
+use data_streams::{DataSink, DataSource, GenericDataSink, GenericDataSource};
+use test::{black_box, Bencher};
+
+const COUNT: usize = 1024;
+
+fn q16_16() -> Vec<u8> {
+	(0..COUNT as i32).flat_map(|i| (i * 0x1_2345).to_be_bytes()).collect()
+}
+
+#[bench]
+fn read_fixed_i32(b: &mut Bencher) {
+	let bytes = q16_16();
+	b.iter(|| {
+		let mut source = black_box(bytes.as_slice());
+		for _ in 0..COUNT {
+			black_box(GenericDataSource::<i32>::read_fixed(&mut source, 16).unwrap());
+		}
+	});
+}
+
+#[bench]
+fn read_fixed_i16_q8(b: &mut Bencher) {
+	let bytes = q16_16();
+	b.iter(|| {
+		let mut source = black_box(bytes.as_slice());
+		for _ in 0..COUNT * 2 {
+			black_box(source.read_fixed_i16_q8().unwrap());
+		}
+	});
+}
+
+#[bench]
+fn write_fixed_i32(b: &mut Bencher) {
+	let mut sink = Vec::with_capacity(COUNT * 4);
+	b.iter(|| {
+		sink.clear();
+		for i in 0..COUNT {
+			GenericDataSink::<i32>::write_fixed(&mut sink, black_box(i as f64 * 1.1), 16).unwrap();
+		}
+		black_box(&sink);
+	});
+}
+
+#[bench]
+fn write_fixed_i16_q8(b: &mut Bencher) {
+	let mut sink = Vec::with_capacity(COUNT * 2);
+	b.iter(|| {
+		sink.clear();
+		for i in 0..COUNT {
+			sink.write_fixed_i16_q8(black_box(i as f32 * 0.1)).unwrap();
+		}
+		black_box(&sink);
+	});
+}
@@ -0,0 +1,71 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![feature(test)]
+
+extern crate test;
+
+use std::io::{BufReader, Cursor, Seek, SeekFrom};
+use bytemuck::{bytes_of_mut, Zeroable};
+use data_streams::{DataSource, GenericDataSource};
+use test::{black_box, Bencher};
+
+const COUNT: usize = 64;
+
+/// A 1KiB record.
+type Record = [u64; 128];
+
+fn bytes() -> Vec<u8> {
+	(0..COUNT * size_of::<Record>()).map(|i| i as u8).collect()
+}
+
+/// Reads like the previous implementation, zeroing the value before reading.
+fn read_zeroed(source: &mut impl DataSource) -> Record {
+	let mut value = Record::zeroed();
+	source.read_exact_bytes(bytes_of_mut(&mut value)).unwrap();
+	value
+}
+
+#[bench]
+fn slice_read_data(b: &mut Bencher) {
+	let bytes = bytes();
+	b.iter(|| {
+		let mut source = black_box(bytes.as_slice());
+		for _ in 0..COUNT {
+			black_box(GenericDataSource::<Record>::read_data(&mut source).unwrap());
+		}
+	});
+}
+
+#[bench]
+fn slice_read_zeroed(b: &mut Bencher) {
+	let bytes = bytes();
+	b.iter(|| {
+		let mut source = black_box(bytes.as_slice());
+		for _ in 0..COUNT {
+			black_box(read_zeroed(&mut source));
+		}
+	});
+}
+
+#[bench]
+fn buf_reader_read_data(b: &mut Bencher) {
+	let mut source = BufReader::new(Cursor::new(bytes()));
+	b.iter(|| {
+		source.seek(SeekFrom::Start(0)).unwrap();
+		for _ in 0..COUNT {
+			black_box(GenericDataSource::<Record>::read_data(&mut source).unwrap());
+		}
+	});
+}
+
+#[bench]
+fn buf_reader_read_zeroed(b: &mut Bencher) {
+	let mut source = BufReader::new(Cursor::new(bytes()));
+	b.iter(|| {
+		source.seek(SeekFrom::Start(0)).unwrap();
+		for _ in 0..COUNT {
+			black_box(read_zeroed(&mut source));
+		}
+	});
+}
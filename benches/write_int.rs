@@ -0,0 +1,44 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![feature(test)]
+
+extern crate test;
+
+use data_streams::{DataSink, Result};
+use test::{black_box, Bencher};
+
+const COUNT: usize = 10_000;
+
+/// Writes through a slice with only `write_bytes`, taking the default integer path.
+struct DefaultSink<'a>(&'a mut [u8]);
+
+impl DataSink for DefaultSink<'_> {
+	fn write_bytes(&mut self, buf: &[u8]) -> Result {
+		self.0.write_bytes(buf)
+	}
+}
+
+#[bench]
+fn slice_write_u32(b: &mut Bencher) {
+	let mut storage = vec![0; COUNT * 4];
+	b.iter(|| {
+		let mut sink = storage.as_mut_slice();
+		for i in 0..COUNT as u32 {
+			sink.write_u32(black_box(i)).unwrap();
+		}
+		black_box(sink);
+	});
+}
+
+#[bench]
+fn default_write_u32(b: &mut Bencher) {
+	let mut storage = vec![0; COUNT * 4];
+	b.iter(|| {
+		let mut sink = DefaultSink(storage.as_mut_slice());
+		for i in 0..COUNT as u32 {
+			sink.write_u32(black_box(i)).unwrap();
+		}
+		black_box(sink.0);
+	});
+}
@@ -0,0 +1,40 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "alloc")]
+
+use alloc::boxed::Box;
+use alloc::vec;
+use crate::SliceSink;
+
+/// A sink writing sequentially into a preallocated boxed slice. Like a mutable
+/// slice, it overflows once full, but keeps ownership of its storage and tracks
+/// the written byte count. The written bytes can be read back out through
+/// [`filled`](SliceSink::filled), which is itself a [source](crate::DataSource).
+///
+/// ```
+/// # use data_streams::{BoxedSliceSink, DataSink, DataSource, Error};
+/// let mut sink = BoxedSliceSink::with_len(6);
+/// sink.write_u32(0xCAFE_BABE)?;
+/// assert_eq!(sink.position(), 4);
+/// assert_eq!(sink.write_bytes(b"xyz"), Err(Error::overflow(1, 2)));
+///
+/// let mut source = sink.filled();
+/// assert_eq!(source.read_u32()?, 0xCAFE_BABE);
+/// assert_eq!(source, b"xy");
+/// # Ok::<_, Error>(())
+/// ```
+pub type BoxedSliceSink = SliceSink<Box<[u8]>>;
+
+impl BoxedSliceSink {
+	/// Creates a sink writing into a zeroed slice of `len` bytes.
+	pub fn with_len(len: usize) -> Self {
+		Self::new(vec![0; len].into_boxed_slice())
+	}
+}
+
+impl From<Box<[u8]>> for BoxedSliceSink {
+	fn from(buf: Box<[u8]>) -> Self {
+		Self::new(buf)
+	}
+}
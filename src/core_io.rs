@@ -14,11 +14,13 @@ impl DataSink for BorrowedBuf<'_> {
 
 impl DataSink for BorrowedCursor<'_> {
 	fn write_bytes(&mut self, buf: &[u8]) -> Result {
-		if buf.len() > self.capacity() {
-			return Err(Error::overflow(self.capacity() - buf.len()))
+		let len = buf.len().min(self.capacity());
+		self.append(&buf[..len]);
+		let remaining = buf.len() - len;
+		if remaining > 0 {
+			Err(Error::overflow(remaining, len))
+		} else {
+			Ok(())
 		}
-		
-		self.append(buf);
-		Ok(())
 	}
 }
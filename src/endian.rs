@@ -0,0 +1,33 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use num_traits::PrimInt;
+
+/// A byte order for reading or writing integers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Endian {
+	/// Big-endian, or network, byte order. Most significant byte first.
+	#[default]
+	Big,
+	/// Little-endian byte order. Least significant byte first.
+	Little,
+}
+
+impl Endian {
+	/// The byte order of the target platform.
+	#[cfg(target_endian = "big")]
+	pub const NATIVE: Self = Self::Big;
+	/// The byte order of the target platform.
+	#[cfg(target_endian = "little")]
+	pub const NATIVE: Self = Self::Little;
+
+	/// Converts an integer from native byte order to this byte order, swapping its
+	/// bytes if they differ.
+	#[inline]
+	pub fn convert<T: PrimInt>(self, value: T) -> T {
+		match self {
+			Self::Big => value.to_be(),
+			Self::Little => value.to_le(),
+		}
+	}
+}
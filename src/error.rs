@@ -8,6 +8,7 @@ use core::ascii;
 #[cfg(feature = "utf8")]
 pub use simdutf8::compat::Utf8Error as SimdUtf8Error;
 use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::num::IntErrorKind;
 #[cfg(feature = "utf8")]
 use core::num::NonZeroU8;
 
@@ -19,7 +20,6 @@ pub enum Error {
 	#[cfg(feature = "std")]
 	Io(std::io::Error),
 	/// An invalid ASCII byte was encountered.
-	#[cfg(feature = "unstable_ascii_char")]
 	Ascii(AsciiError),
 	/// Invalid UTF-8 bytes were encountered.
 	#[cfg(feature = "utf8")]
@@ -30,16 +30,39 @@ pub enum Error {
 	/// A sink reached a hard storage limit, causing an overflow while writing. An
 	/// example is a mutable slice, which can't write more bytes than its length.
 	Overflow {
-		/// The byte count remaining in the attempted read operation.
-		remaining: usize
+		/// The byte count remaining in the attempted write operation.
+		remaining: usize,
+		/// The byte count written to the sink before it overflowed.
+		written: usize
 	},
 	/// Premature end-of-stream.
 	End {
 		/// The total required byte count.
-		required_count: usize
+		required_count: usize,
+		/// The byte count consumed from the stream before it ended. If this is zero,
+		/// the read can be retried from the same position once more data arrives.
+		read_count: usize
 	},
 	/// A "read to end" method was called on a source with no defined end.
 	NoEnd,
+	/// A checksum read from a source didn't match the data it was computed over.
+	#[cfg(feature = "digest")]
+	ChecksumMismatch,
+	/// An integer doesn't fit in the target type or encoded byte width.
+	OutOfRange {
+		/// The encoded byte width of the integer.
+		width: usize
+	},
+	/// A source read a byte count which isn't a multiple of the requested alignment,
+	/// indicating a faulty [`read_aligned_bytes`] implementation.
+	///
+	/// [`read_aligned_bytes`]: crate::DataSource::read_aligned_bytes
+	Misaligned {
+		/// The byte count read.
+		read_count: usize,
+		/// The requested alignment.
+		alignment: usize
+	},
 	/// Buffer size is insufficient to buffer a read operation.
 	InsufficientBuffer {
 		/// The buffer's spare capacity.
@@ -47,31 +70,98 @@ pub enum Error {
 		/// The total required byte count.
 		required_count: usize
 	},
+	/// A source read a byte count outside the bounds given by its [`SourceSize`]
+	/// implementation, indicating a faulty implementation.
+	///
+	/// [`SourceSize`]: crate::markers::source::SourceSize
+	SizeMismatch {
+		/// The byte count read.
+		read_count: u64,
+		/// The lower bound given by the source.
+		lower_bound: u64,
+		/// The upper bound given by the source, if any.
+		upper_bound: Option<u64>
+	},
+	/// A byte buffer's address isn't aligned for the type of data read into it.
+	UnalignedBuffer {
+		/// The alignment required by the type.
+		alignment: usize
+	},
+	/// Decimal integer text couldn't be parsed.
+	ParseInt {
+		/// The reason parsing failed.
+		kind: IntErrorKind
+	},
+	/// Bytes read from a source, such as a file format's magic number, didn't match
+	/// the expected bytes.
+	Mismatch {
+		/// The expected bytes.
+		expected: MagicBytes,
+		/// The bytes found instead.
+		found: MagicBytes
+	},
 }
 
 impl Error {
 	/// Creates an ASCII error.
+	///
+	/// # Panics
+	///
+	/// Panics if `consumed_count` is less than `valid_up_to`.
 	#[inline]
-	#[cfg(feature = "unstable_ascii_char")]
 	pub const fn invalid_ascii(invalid_byte: u8, valid_up_to: usize, consumed_count: usize) -> Self {
 		assert!(consumed_count >= valid_up_to);
 		Self::Ascii(AsciiError { invalid_byte, valid_up_to, consumed_count })
 	}
 	/// Creates an overflow error.
 	#[inline]
-	pub const fn overflow(remaining: usize) -> Self {
-		Self::Overflow { remaining }
+	pub const fn overflow(remaining: usize, written: usize) -> Self {
+		Self::Overflow { remaining, written }
 	}
 	/// Creates an end-of-stream error.
 	#[inline]
-	pub const fn end(required_count: usize) -> Self {
-		Self::End { required_count }
+	pub const fn end(required_count: usize, read_count: usize) -> Self {
+		Self::End { required_count, read_count }
+	}
+	/// Creates an integer out-of-range error.
+	#[inline]
+	pub const fn out_of_range(width: usize) -> Self {
+		Self::OutOfRange { width }
+	}
+	/// Creates a misaligned read error.
+	#[inline]
+	pub const fn misaligned(read_count: usize, alignment: usize) -> Self {
+		Self::Misaligned { read_count, alignment }
 	}
 	/// Creates an insufficient buffer capacity error.
 	#[inline]
 	pub const fn insufficient_buffer(spare_capacity: usize, required_count: usize) -> Self {
 		Self::InsufficientBuffer { spare_capacity, required_count }
 	}
+	/// Creates a source size mismatch error.
+	#[inline]
+	pub const fn size_mismatch(read_count: u64, lower_bound: u64, upper_bound: Option<u64>) -> Self {
+		Self::SizeMismatch { read_count, lower_bound, upper_bound }
+	}
+	/// Creates an unaligned buffer error.
+	#[inline]
+	pub const fn unaligned_buffer(alignment: usize) -> Self {
+		Self::UnalignedBuffer { alignment }
+	}
+	/// Creates an integer parsing error.
+	#[inline]
+	pub const fn parse_int(kind: IntErrorKind) -> Self {
+		Self::ParseInt { kind }
+	}
+	/// Creates a byte mismatch error. Only the first [`MagicBytes::CAPACITY`] bytes
+	/// of each slice are kept.
+	#[inline]
+	pub const fn mismatch(expected: &[u8], found: &[u8]) -> Self {
+		Self::Mismatch {
+			expected: MagicBytes::new(expected),
+			found: MagicBytes::new(found)
+		}
+	}
 }
 
 #[cfg(feature = "std")]
@@ -79,16 +169,23 @@ impl std::error::Error for Error {
 	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
 		match self {
 			Self::Io(error) => Some(error),
-			#[cfg(feature = "unstable_ascii_char")]
 			Self::Ascii(_) => None,
 			#[cfg(feature = "utf8")]
 			Self::Utf8(error) => error.source(),
 			#[cfg(feature = "alloc")]
 			Self::Allocation(error) => Some(error),
+			#[cfg(feature = "digest")]
+			Self::ChecksumMismatch => None,
 			Self::Overflow { .. } |
 			Self::End { .. } |
 			Self::NoEnd |
-			Self::InsufficientBuffer { .. } => None,
+			Self::OutOfRange { .. } |
+			Self::Misaligned { .. } |
+			Self::InsufficientBuffer { .. } |
+			Self::SizeMismatch { .. } |
+			Self::UnalignedBuffer { .. } |
+			Self::ParseInt { .. } |
+			Self::Mismatch { .. } => None,
 		}
 	}
 }
@@ -98,18 +195,139 @@ impl Display for Error {
 		match self {
 			#[cfg(feature = "std")]
 			Self::Io(error) => Display::fmt(error, f),
-			#[cfg(feature = "unstable_ascii_char")]
 			Self::Ascii(error) => Display::fmt(error, f),
 			#[cfg(feature = "utf8")]
 			Self::Utf8(error) => Display::fmt(error, f),
 			#[cfg(feature = "alloc")]
 			Self::Allocation(error) => Display::fmt(error, f),
-			Self::Overflow { remaining } => write!(f, "sink overflowed with {remaining} bytes remaining to write"),
-			Self::End { required_count } => write!(f, "premature end-of-stream when reading {required_count} bytes"),
+			Self::Overflow {
+				remaining, written
+			} => write!(f, "sink overflowed with {remaining} bytes remaining to write ({written} bytes written)"),
+			Self::End {
+				required_count, read_count
+			} => write!(f, "premature end-of-stream when reading {required_count} bytes ({read_count} bytes read)"),
 			Self::NoEnd => write!(f, "cannot read to end of infinite source"),
+			#[cfg(feature = "digest")]
+			Self::ChecksumMismatch => write!(f, "checksum mismatch"),
+			Self::OutOfRange { width } => write!(f, "integer out of range for {width}-byte encoding"),
+			Self::Misaligned {
+				read_count, alignment
+			} => write!(f, "read {read_count} bytes, which is not a multiple of the alignment ({alignment})"),
 			Self::InsufficientBuffer {
 				spare_capacity, required_count
 			} => write!(f, "insufficient buffer capacity ({spare_capacity}) to read {required_count} bytes"),
+			Self::SizeMismatch {
+				read_count, lower_bound, upper_bound: Some(upper_bound)
+			} => write!(f, "read {read_count} bytes from a source sized {lower_bound}..={upper_bound} bytes"),
+			Self::SizeMismatch {
+				read_count, lower_bound, upper_bound: None
+			} => write!(f, "read {read_count} bytes from a source sized at least {lower_bound} bytes"),
+			Self::UnalignedBuffer { alignment } => write!(f, "buffer is not aligned to {alignment} bytes"),
+			Self::ParseInt { kind } => write!(f, "invalid decimal integer: {}", match kind {
+				IntErrorKind::Empty => "no digits",
+				IntErrorKind::InvalidDigit => "invalid digit",
+				IntErrorKind::PosOverflow => "too large for the target type",
+				IntErrorKind::NegOverflow => "too small for the target type",
+				_ => "unknown error"
+			}),
+			Self::Mismatch {
+				expected, found
+			} => write!(f, "expected bytes [{expected}], found [{found}]"),
+		}
+	}
+}
+
+impl Clone for Error {
+	/// Clones the error. IO errors are cloned by their OS error code if present,
+	/// otherwise by their kind and message; the original inner error is not kept.
+	fn clone(&self) -> Self {
+		match self {
+			#[cfg(feature = "std")]
+			Self::Io(error) => Self::Io(
+				error.raw_os_error().map_or_else(
+					|| std::io::Error::new(error.kind(), error.to_string()),
+					std::io::Error::from_raw_os_error
+				)
+			),
+			Self::Ascii(error) => Self::Ascii(*error),
+			#[cfg(feature = "utf8")]
+			Self::Utf8(error) => Self::Utf8(*error),
+			#[cfg(feature = "alloc")]
+			Self::Allocation(error) => Self::Allocation(error.clone()),
+			&Self::Overflow { remaining, written } => Self::Overflow { remaining, written },
+			&Self::End {
+				required_count, read_count
+			} => Self::End { required_count, read_count },
+			Self::NoEnd => Self::NoEnd,
+			#[cfg(feature = "digest")]
+			Self::ChecksumMismatch => Self::ChecksumMismatch,
+			&Self::OutOfRange { width } => Self::OutOfRange { width },
+			&Self::Misaligned {
+				read_count, alignment
+			} => Self::Misaligned { read_count, alignment },
+			&Self::InsufficientBuffer {
+				spare_capacity, required_count
+			} => Self::InsufficientBuffer { spare_capacity, required_count },
+			&Self::SizeMismatch {
+				read_count, lower_bound, upper_bound
+			} => Self::SizeMismatch { read_count, lower_bound, upper_bound },
+			&Self::UnalignedBuffer { alignment } => Self::UnalignedBuffer { alignment },
+			&Self::ParseInt { kind } => Self::ParseInt { kind },
+			&Self::Mismatch { expected, found } => Self::Mismatch { expected, found },
+		}
+	}
+}
+
+impl PartialEq for Error {
+	/// Compares errors by value. IO errors are equal if their [kinds] are equal.
+	///
+	/// [kinds]: std::io::Error::kind
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			#[cfg(feature = "std")]
+			(Self::Io(a), Self::Io(b)) => a.kind() == b.kind(),
+			(Self::Ascii(a), Self::Ascii(b)) => a == b,
+			#[cfg(feature = "utf8")]
+			(Self::Utf8(a), Self::Utf8(b)) => a == b,
+			#[cfg(feature = "alloc")]
+			(Self::Allocation(a), Self::Allocation(b)) => a == b,
+			(
+				Self::Overflow { remaining: a_remaining, written: a_written },
+				Self::Overflow { remaining: b_remaining, written: b_written }
+			) => a_remaining == b_remaining && a_written == b_written,
+			(
+				Self::End { required_count: a_required, read_count: a_read },
+				Self::End { required_count: b_required, read_count: b_read }
+			) => a_required == b_required && a_read == b_read,
+			(Self::NoEnd, Self::NoEnd) => true,
+			#[cfg(feature = "digest")]
+			(Self::ChecksumMismatch, Self::ChecksumMismatch) => true,
+			(
+				Self::OutOfRange { width: a },
+				Self::OutOfRange { width: b }
+			) => a == b,
+			(
+				Self::Misaligned { read_count: a_read, alignment: a_alignment },
+				Self::Misaligned { read_count: b_read, alignment: b_alignment }
+			) => a_read == b_read && a_alignment == b_alignment,
+			(
+				Self::InsufficientBuffer { spare_capacity: a_spare, required_count: a_required },
+				Self::InsufficientBuffer { spare_capacity: b_spare, required_count: b_required }
+			) => a_spare == b_spare && a_required == b_required,
+			(
+				Self::SizeMismatch { read_count: a_read, lower_bound: a_lower, upper_bound: a_upper },
+				Self::SizeMismatch { read_count: b_read, lower_bound: b_lower, upper_bound: b_upper }
+			) => a_read == b_read && a_lower == b_lower && a_upper == b_upper,
+			(
+				Self::UnalignedBuffer { alignment: a },
+				Self::UnalignedBuffer { alignment: b }
+			) => a == b,
+			(Self::ParseInt { kind: a }, Self::ParseInt { kind: b }) => a == b,
+			(
+				Self::Mismatch { expected: a_expected, found: a_found },
+				Self::Mismatch { expected: b_expected, found: b_found }
+			) => a_expected == b_expected && a_found == b_found,
+			_ => false
 		}
 	}
 }
@@ -138,7 +356,6 @@ impl From<Utf8Error> for Error {
 	}
 }
 
-#[cfg(feature = "unstable_ascii_char")]
 impl From<AsciiError> for Error {
 	#[inline]
 	fn from(value: AsciiError) -> Self {
@@ -154,6 +371,55 @@ impl From<TryReserveError> for Error {
 	}
 }
 
+/// A short byte sequence held inline in an [`Error::Mismatch`], such as a magic
+/// number. Up to [`CAPACITY`](Self::CAPACITY) bytes are kept; longer sequences are
+/// truncated.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MagicBytes {
+	bytes: [u8; Self::CAPACITY],
+	len: u8,
+}
+
+impl MagicBytes {
+	/// The maximum byte count held.
+	pub const CAPACITY: usize = 16;
+
+	/// Copies up to [`CAPACITY`](Self::CAPACITY) bytes from `bytes`.
+	#[must_use]
+	pub const fn new(bytes: &[u8]) -> Self {
+		let mut buf = [0; Self::CAPACITY];
+		let mut len = 0;
+		while len < bytes.len() && len < Self::CAPACITY {
+			buf[len] = bytes[len];
+			len += 1;
+		}
+		#[allow(clippy::cast_possible_truncation)]
+		Self { bytes: buf, len: len as u8 }
+	}
+	/// Returns the held bytes.
+	#[inline]
+	#[must_use]
+	pub fn as_bytes(&self) -> &[u8] { &self.bytes[..self.len as usize] }
+}
+
+impl AsRef<[u8]> for MagicBytes {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl Display for MagicBytes {
+	/// Formats the bytes as space-separated hexadecimal.
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		for (i, byte) in self.as_bytes().iter().enumerate() {
+			if i > 0 {
+				f.write_str(" ")?;
+			}
+			write!(f, "{byte:02X}")?;
+		}
+		Ok(())
+	}
+}
+
 #[cfg(feature = "utf8")]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Utf8Error {
@@ -261,11 +527,9 @@ impl Utf8Error {
 
 #[cfg(feature = "utf8")]
 impl Utf8Error {
-	#[cfg(any(feature = "unstable_specialization", feature = "alloc"))]
 	pub(crate) fn set_offset(&mut self, offset: usize) {
 		self.offset += offset;
 	}
-	#[cfg(feature = "unstable_specialization")]
 	pub(crate) fn with_offset(mut self, offset: usize) -> Self {
 		self.set_offset(offset);
 		self
@@ -298,7 +562,8 @@ impl From<SimdUtf8Error> for Utf8Error {
 	}
 }
 
-#[cfg(feature = "unstable_ascii_char")]
+/// An error returned when a non-ASCII byte is read. The bytes up to
+/// [`valid_up_to`](Self::valid_up_to) are valid ASCII.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct AsciiError {
 	/// The invalid byte, in range `128..256`.
@@ -310,7 +575,6 @@ pub struct AsciiError {
 	pub consumed_count: usize,
 }
 
-#[cfg(feature = "unstable_ascii_char")]
 impl AsciiError {
 	/// Returns the invalid byte.
 	#[inline]
@@ -330,6 +594,29 @@ impl AsciiError {
 	#[inline]
 	#[must_use]
 	pub const fn unchecked_count(&self) -> usize { self.consumed_count.saturating_sub(1 + self.valid_up_to) }
+	/// Returns the validated part of a slice.
+	///
+	/// # Panics
+	///
+	/// Panics if the slice is shorter than the valid length in the error.
+	#[must_use]
+	pub fn valid_bytes<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+		&bytes[..self.valid_up_to]
+	}
+	/// Splits a slice at the valid ASCII index, returning the validated bytes and
+	/// the consumed bytes from the invalid byte onward.
+	///
+	/// # Panics
+	///
+	/// Panics if the slice is shorter than the consumed count.
+	#[must_use]
+	pub fn split_valid_bytes<'a>(&self, bytes: &'a [u8]) -> (&'a [u8], &'a [u8]) {
+		bytes[..self.consumed_count].split_at(self.valid_up_to)
+	}
+}
+
+#[cfg(feature = "unstable_ascii_char")]
+impl AsciiError {
 	/// Returns the validated part of a slice as ASCII.
 	/// 
 	/// # Panics
@@ -394,13 +681,77 @@ impl AsciiError {
 	}
 }
 
-#[cfg(all(feature = "std", feature = "unstable_ascii_char"))]
+#[cfg(feature = "std")]
 impl std::error::Error for AsciiError { }
 
-#[cfg(feature = "unstable_ascii_char")]
 impl Display for AsciiError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
 		let Self { invalid_byte, valid_up_to, .. } = self;
 		write!(f, "non-ASCII byte {invalid_byte:#X} at index {valid_up_to}")
 	}
 }
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+	use std::io::{Error as IoError, ErrorKind};
+	use core::num::IntErrorKind;
+	use super::Error;
+
+	#[test]
+	fn io_eq_by_kind() {
+		assert_eq!(
+			Error::Io(IoError::new(ErrorKind::UnexpectedEof, "a")),
+			Error::Io(IoError::new(ErrorKind::UnexpectedEof, "b"))
+		);
+		assert_ne!(
+			Error::Io(ErrorKind::UnexpectedEof.into()),
+			Error::Io(ErrorKind::Interrupted.into())
+		);
+		assert_ne!(Error::Io(ErrorKind::UnexpectedEof.into()), Error::end(1, 0));
+	}
+
+	#[test]
+	fn io_clone() {
+		let error = Error::Io(IoError::new(ErrorKind::InvalidData, "bad data"));
+		let Error::Io(clone) = error.clone() else { unreachable!() };
+		assert_eq!(clone.kind(), ErrorKind::InvalidData);
+		assert_eq!(clone.to_string(), "bad data");
+
+		let error = Error::Io(IoError::from_raw_os_error(2));
+		let Error::Io(clone) = error.clone() else { unreachable!() };
+		assert_eq!(clone.raw_os_error(), Some(2));
+	}
+
+	#[test]
+	fn clone_eq() {
+		let errors = [
+			Error::overflow(1, 2),
+			Error::end(2, 1),
+			Error::NoEnd,
+			Error::out_of_range(5),
+			Error::misaligned(6, 4),
+			Error::insufficient_buffer(3, 4),
+			Error::size_mismatch(2, 4, Some(8)),
+			Error::size_mismatch(2, 4, None),
+			Error::unaligned_buffer(4),
+			Error::parse_int(IntErrorKind::PosOverflow),
+			Error::mismatch(b"PNG", b"GIF"),
+			Error::invalid_ascii(0xFF, 2, 4),
+		];
+		for error in errors {
+			assert_eq!(error.clone(), error);
+		}
+		assert_ne!(Error::end(1, 0), Error::end(2, 0));
+		assert_ne!(Error::end(2, 0), Error::end(2, 1));
+	}
+
+	#[test]
+	fn mismatch_display() {
+		assert_eq!(
+			Error::mismatch(b"\x89PNG", b"GIF8").to_string(),
+			"expected bytes [89 50 4E 47], found [47 49 46 38]"
+		);
+		let Error::Mismatch { expected, .. } = Error::mismatch(&[0; 20], &[]) else { unreachable!() };
+		assert_eq!(expected.as_bytes(), [0; 16]);
+	}
+}
@@ -0,0 +1,67 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use num_traits::float::FloatCore;
+use num_traits::{cast, PrimInt};
+
+/// Converts a fixed-point integer with `frac_bits` fractional bits to a float.
+pub fn fixed_to_float<T: PrimInt>(value: T, frac_bits: u32) -> f64 {
+	check_frac_bits::<T>(frac_bits);
+	// Conversion from a primitive integer to f64 never fails.
+	value.to_f64().unwrap_or_default() / exp2(frac_bits)
+}
+
+/// Converts a float to a fixed-point integer with `frac_bits` fractional bits,
+/// rounding to the nearest integer with ties away from zero, and saturating if the
+/// result is out of range. NaN is converted to zero.
+pub fn float_to_fixed<T: PrimInt>(value: f64, frac_bits: u32) -> T {
+	check_frac_bits::<T>(frac_bits);
+	let scaled = FloatCore::round(value * exp2(frac_bits));
+	cast::<f64, T>(scaled).unwrap_or_else(||
+		if scaled.is_nan() {
+			T::zero()
+		} else if scaled.is_sign_positive() {
+			T::max_value()
+		} else {
+			T::min_value()
+		}
+	)
+}
+
+fn check_frac_bits<T: PrimInt>(frac_bits: u32) {
+	let bits = T::zero().count_zeros();
+	assert!(frac_bits <= bits, "fractional bit count {frac_bits} exceeds the integer width of {bits} bits");
+}
+
+/// Returns `2^exp`, for `exp` in range `0..=1023`.
+fn exp2(exp: u32) -> f64 {
+	f64::from_bits(u64::from(1023 + exp) << 52)
+}
+
+#[cfg(test)]
+mod test {
+	use super::{fixed_to_float, float_to_fixed};
+
+	#[test]
+	fn round_trip() {
+		// These values are exactly representable, so their bits are compared.
+		assert_eq!(fixed_to_float(-0x18000i32, 16).to_bits(), (-1.5f64).to_bits());
+		assert_eq!(float_to_fixed::<i32>(-1.5, 16), -0x18000);
+		assert_eq!(fixed_to_float(u8::MAX, 8).to_bits(), (255.0f64 / 256.0).to_bits());
+	}
+
+	#[test]
+	fn round_nearest() {
+		assert_eq!(float_to_fixed::<i16>(0.5 / 256.0, 8), 1);
+		assert_eq!(float_to_fixed::<i16>(-0.5 / 256.0, 8), -1);
+		assert_eq!(float_to_fixed::<i16>(0.4 / 256.0, 8), 0);
+	}
+
+	#[test]
+	fn saturate() {
+		assert_eq!(float_to_fixed::<i16>(1000.0, 8), i16::MAX);
+		assert_eq!(float_to_fixed::<i16>(-1000.0, 8), i16::MIN);
+		assert_eq!(float_to_fixed::<u16>(-1.0, 8), 0);
+		assert_eq!(float_to_fixed::<i32>(f64::NAN, 8), 0);
+	}
+}
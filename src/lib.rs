@@ -69,12 +69,18 @@
 //! - `utf8`: Enables reading UTF-8-validated data from sources, and writing to [`String`]s, using a
 //!   very fast SIMD validation algorithm from the [`simdutf8`](https://github.com/rusticstuff/simdutf8)
 //!   crate. UTF-8 can be written to sinks without this feature.
+//! - `digest`: Provides the [`Verified`] source adapter, checking a trailing checksum with a hasher
+//!   from the [`digest`](https://github.com/RustCrypto/traits/tree/master/digest) crate.
 //! - `unstable`: Provides unstable features only present on the nightly compiler. Enables:
-//!   - `unstable_borrowed_buf`: Provides [`DataSource`] impls for [`BorrowedBuf`](core::io::BorrowedBuf)
-//!     and [`BorrowedCursor`](core::io::BorrowedCursor).
+//!   - `unstable_borrowed_buf`: Provides [`DataSink`] impls for [`BorrowedBuf`](core::io::BorrowedBuf)
+//!     and [`BorrowedCursor`](core::io::BorrowedCursor), and reading into uninitialized memory
+//!     with [`DataSource::read_into_borrowed`].
 //!   - `unstable_specialization`: Enables trait specialization, providing a default [`DataSource`]
-//!     for impls of [`BufferAccess`].
-//!   - `unstable_uninit_slice`: Provides a [`DataSink`] impl for `&mut [MaybeUninit<u8>]`.
+//!     for impls of [`BufferAccess`], and reading [`GenericDataSource::read_data`] directly from
+//!     the buffer without zero-filling.
+//!   - `unstable_uninit_slice`: Provides a [`DataSink`] impl for `&mut [MaybeUninit<u8>]`, and
+//!     copies from in-memory sources directly into uninitialized memory with
+//!     [`DataSource::read_into_uninit`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "unstable_ascii_char", feature(ascii_char))]
@@ -96,7 +102,10 @@
 extern crate alloc;
 extern crate core;
 
+mod boxed;
+mod endian;
 mod error;
+mod fixed;
 mod source;
 mod sink;
 mod slice;
@@ -108,20 +117,36 @@ mod wrappers;
 
 pub mod markers {
 	pub mod source {
-		pub use crate::source::markers::{InfiniteSource, SourceSize};
+		pub use crate::source::markers::{infinite_skip, InfiniteSource, SourceSize};
 	}
 }
 
-pub use error::Error;
-#[cfg(feature = "unstable_ascii_char")]
+#[cfg(feature = "std")]
+pub use std_io::retry_interrupted;
+
+/// Buffered file sources and sinks.
+#[cfg(feature = "std")]
+pub mod fs {
+	pub use crate::std_io::{create, open};
+}
+
+#[cfg(feature = "alloc")]
+pub use boxed::BoxedSliceSink;
+pub use slice::SliceSink;
+pub use endian::Endian;
+pub use error::{Error, MagicBytes};
 pub use error::AsciiError;
 #[cfg(feature = "utf8")]
 pub use error::{Utf8Error, Utf8ErrorKind, SimdUtf8Error};
-pub use sink::{DataSink, GenericDataSink};
+pub use sink::{CountingSink, DatagramSink, DataSink, Escape, EscapingSink, FlushGuard, GenericDataSink};
 #[cfg(feature = "alloc")]
-pub use sink::VecSink;
-pub use source::{BufferAccess, DataSource, GenericDataSource};
+pub use sink::{BufferedDatagramSink, CappedVecSink, VecSink};
+pub use source::{read_array, ArraySource, BufferAccess, DataSource, GenericDataSource, IterSource, PushbackSource, SliceSource, StrSource, UnescapingSource};
+#[cfg(feature = "utf8")]
+pub use source::Chars;
+#[cfg(feature = "digest")]
+pub use source::Verified;
 #[cfg(feature = "alloc")]
-pub use source::VecSource;
+pub use source::{ExtendFromSource, VecSource};
 
 pub type Result<T = (), E = Error> = core::result::Result<T, E>;
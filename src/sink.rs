@@ -7,9 +7,26 @@ use alloc::vec::Vec;
 use alloc::string::String;
 #[cfg(feature = "unstable_ascii_char")]
 use core::ascii;
-use num_traits::PrimInt;
-use bytemuck::{bytes_of, Pod};
-use crate::Result;
+use core::fmt::{self, Display, Write as _};
+use num_traits::{PrimInt, ToBytes};
+use bytemuck::{bytes_of, cast_slice, Pod};
+use crate::{Endian, Error, Result};
+use crate::fixed::float_to_fixed;
+
+mod capped;
+mod counting;
+mod datagram;
+mod escape;
+mod flush;
+
+#[cfg(feature = "alloc")]
+pub use capped::CappedVecSink;
+pub use counting::CountingSink;
+pub use datagram::DatagramSink;
+#[cfg(feature = "alloc")]
+pub use datagram::BufferedDatagramSink;
+pub use escape::{Escape, EscapingSink};
+pub use flush::FlushGuard;
 
 /// A sink stream of data.
 pub trait DataSink {
@@ -23,6 +40,235 @@ pub trait DataSink {
 	///
 	/// [`Write::write_all`]: io::Write::write_all
 	fn write_bytes(&mut self, buf: &[u8]) -> Result;
+	/// Writes as many bytes from `buf` as the sink accepts without blocking,
+	/// returning the number of bytes written. On non-blocking sinks, this stops
+	/// when the sink would block, returning `Ok(0)` if nothing could be written.
+	/// The caller can retry the rest of `buf` once the sink is writable again,
+	/// such as in a poll loop.
+	///
+	/// Unlike [`write_bytes`], which returns a [`WouldBlock`] error after writing
+	/// an unknown count of bytes, this always reports the bytes written, so a
+	/// framed protocol can resume where it left off.
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes. Returns any IO errors encountered, other than
+	/// [`WouldBlock`]. Interrupted writes are retried.
+	///
+	/// # Implementation
+	///
+	/// The default implementation writes all of `buf` with [`write_bytes`]. Sinks
+	/// which may block, such as non-blocking sockets, override it.
+	///
+	/// [`write_bytes`]: Self::write_bytes
+	/// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+	fn try_write_bytes(&mut self, buf: &[u8]) -> Result<usize> {
+		self.write_bytes(buf)?;
+		Ok(buf.len())
+	}
+	/// Reserves capacity for at least `additional` more bytes, if the sink can grow.
+	/// Writing a large payload of known size can call this first to avoid repeated
+	/// reallocation. Sinks with fixed storage ignore it.
+	///
+	/// Growable types such as [`Vec`] have an inherent `reserve` method, which is
+	/// picked over this one on concrete values. Call it as `DataSink::reserve` there.
+	///
+	/// ```
+	/// # use data_streams::DataSink;
+	/// let mut sink = Vec::new();
+	/// DataSink::reserve(&mut sink, 64)?;
+	/// assert!(sink.capacity() >= 64);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// May return [`Allocation`](Error::Allocation) if capacity cannot be allocated.
+	///
+	/// [`Vec`]: alloc::vec::Vec
+	fn reserve(&mut self, additional: usize) -> Result {
+		let _ = additional;
+		Ok(())
+	}
+	/// Reserves capacity for exactly `additional` more bytes, if the sink can grow.
+	/// Unlike [`reserve`], this doesn't over-allocate to amortize future growth, so
+	/// calling it before the final write of a known total size leaves no wasted
+	/// capacity. This is slower if more writes follow, which may reallocate each
+	/// time. Sinks with fixed storage ignore it.
+	///
+	/// ```
+	/// # use data_streams::DataSink;
+	/// let mut sink = vec![0; 10];
+	/// DataSink::reserve_exact(&mut sink, 1)?;
+	/// sink.write_u8(1)?;
+	/// assert_eq!(sink.capacity(), 11);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// May return [`Allocation`](Error::Allocation) if capacity cannot be allocated.
+	///
+	/// [`reserve`]: Self::reserve
+	fn reserve_exact(&mut self, additional: usize) -> Result {
+		let _ = additional;
+		Ok(())
+	}
+	/// Flushes any buffered bytes to the underlying stream. Sinks without a buffer
+	/// do nothing. Equivalent to [`Write::flush`].
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered.
+	///
+	/// [`Write::flush`]: io::Write::flush
+	fn flush(&mut self) -> Result { Ok(()) }
+	/// Returns a guard which [flushes](Self::flush) the sink when dropped, ensuring
+	/// writes made in a scope are flushed at its end.
+	///
+	/// ```
+	/// # use std::io::BufWriter;
+	/// # use data_streams::DataSink;
+	/// let mut sink = BufWriter::new(Vec::new());
+	/// {
+	///     let mut guard = sink.flush_guard();
+	///     guard.write_u16(0xCAFE)?;
+	/// }
+	/// assert_eq!(sink.get_ref(), &[0xCA, 0xFE]);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// Errors from a flush on drop are ignored; this is a convenience, not a
+	/// substitute for handling them. Call [`FlushGuard::finish`] to get the result.
+	fn flush_guard(&mut self) -> FlushGuard<'_, Self> where Self: Sized {
+		FlushGuard::new(self)
+	}
+	/// Writes an array with a size of `N` bytes.
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes.
+	fn write_array<const N: usize>(&mut self, arr: &[u8; N]) -> Result where Self: Sized {
+		self.write_bytes(arr)
+	}
+	/// Writes all bytes produced by an iterator, without collecting them into an
+	/// intermediate buffer first.
+	///
+	/// ```
+	/// # use data_streams::DataSink;
+	/// let mut sink = Vec::new();
+	/// sink.write_iter("abc".bytes().map(|b| b.to_ascii_uppercase()))?;
+	/// assert_eq!(sink, b"ABC");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes. The remaining count includes the lower bound of the bytes
+	/// left in the iterator, which is exact for iterators with a known length. The
+	/// written count includes all bytes written from the iterator.
+	///
+	/// # Implementation
+	///
+	/// By default, this writes the bytes in small chunks with [`write_bytes`].
+	/// Growable sinks extend themselves from the iterator directly. A UTF-8 error
+	/// for a codepoint split across chunks is not returned; its bytes are written
+	/// with the next chunk instead.
+	///
+	/// [`write_bytes`]: Self::write_bytes
+	fn write_iter(&mut self, iter: impl IntoIterator<Item = u8>) -> Result where Self: Sized {
+		let mut iter = iter.into_iter();
+		let mut buf = [0; 64];
+		let mut total = 0;
+		let mut carried = 0;
+		loop {
+			let mut len = carried;
+			// The slice must come first, to avoid pulling an extra byte once it's full.
+			for (dst, byte) in buf[carried..].iter_mut().zip(&mut iter) {
+				*dst = byte;
+				len += 1;
+			}
+
+			if len == 0 {
+				break Ok(())
+			}
+
+			carried = 0;
+			match self.write_bytes(&buf[..len]) {
+				Ok(()) => total += len,
+				// A codepoint split by the end of a full chunk may be completed by the
+				// next, so carry its bytes over rather than failing.
+				#[cfg(feature = "utf8")]
+				Err(Error::Utf8(error)) if error.error_len().is_none() && len == buf.len() => {
+					let valid = error.valid_up_to();
+					total += valid;
+					buf.copy_within(valid..len, 0);
+					carried = len - valid;
+				}
+				#[cfg(feature = "utf8")]
+				Err(Error::Utf8(error)) => break Err(error.with_offset(total).into()),
+				Err(Error::Overflow { remaining, written }) =>
+					break Err(Error::overflow(remaining + iter.size_hint().0, total + written)),
+				Err(error) => break Err(error)
+			}
+		}
+	}
+	/// Writes all bytes from `buf` in pieces of at most `chunk` bytes, calling
+	/// `on_progress` with the total count of bytes written after each piece. This
+	/// allows reporting progress on large writes, or pacing writes to rate-limited
+	/// sinks.
+	///
+	/// ```
+	/// # use data_streams::DataSink;
+	/// let mut sink = Vec::new();
+	/// let mut progress = Vec::new();
+	/// sink.write_bytes_chunked(b"Hello, World!", 5, |written| progress.push(written))?;
+	/// assert_eq!(sink, b"Hello, World!");
+	/// assert_eq!(progress, [5, 10, 13]);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes. The remaining and written counts span all of `buf`. The
+	/// callback isn't called for the overflowing piece.
+	///
+	/// # Panics
+	///
+	/// Panics if `chunk` is zero.
+	///
+	/// # Implementation
+	///
+	/// The default implementation calls [`write_bytes`] on each piece.
+	///
+	/// [`write_bytes`]: Self::write_bytes
+	fn write_bytes_chunked(
+		&mut self,
+		buf: &[u8],
+		chunk: usize,
+		mut on_progress: impl FnMut(usize)
+	) -> Result where Self: Sized {
+		assert_ne!(chunk, 0, "chunk size must be non-zero");
+		let mut total = 0;
+		for piece in buf.chunks(chunk) {
+			match self.write_bytes(piece) {
+				Ok(()) => total += piece.len(),
+				Err(Error::Overflow { written, .. }) =>
+					return Err(Error::overflow(buf.len() - total - written, total + written)),
+				Err(error) => return Err(error)
+			}
+			on_progress(total);
+		}
+		Ok(())
+	}
 	/// Writes a UTF-8 string.
 	///
 	/// # Errors
@@ -33,16 +279,67 @@ pub trait DataSink {
 	fn write_utf8(&mut self, value: &str) -> Result {
 		self.write_bytes(value.as_bytes())
 	}
-	/// Writes a single UTF-8 codepoint.
+	/// Writes bytes as UTF-8, replacing any invalid sequences with the replacement
+	/// character `U+FFFD`, as [`String::from_utf8_lossy`] does. The written bytes
+	/// are always valid UTF-8, making this useful for writing untrusted input to
+	/// text sinks.
 	///
 	/// # Errors
 	///
 	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
 	/// storage limit. In the case, the stream is filled completely, excluding the
 	/// overflowing bytes.
-	fn write_utf8_codepoint(&mut self, value: char) -> Result {
+	///
+	/// [`String::from_utf8_lossy`]: alloc::string::String::from_utf8_lossy
+	#[cfg(feature = "utf8")]
+	fn write_utf8_lossy(&mut self, bytes: &[u8]) -> Result {
+		for chunk in bytes.utf8_chunks() {
+			self.write_utf8(chunk.valid())?;
+			if !chunk.invalid().is_empty() {
+				self.write_utf8_codepoint(char::REPLACEMENT_CHARACTER)?;
+			}
+		}
+		Ok(())
+	}
+	/// Writes a single UTF-8 codepoint, returning its encoded width in bytes, from
+	/// one to four. This is useful to track the position when writing text.
+	///
+	/// ```
+	/// # use data_streams::DataSink;
+	/// let mut sink = Vec::new();
+	/// assert_eq!(sink.write_char('a')?, 1);
+	/// assert_eq!(sink.write_char('🦀')?, 4);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes. Mutable slices and the sinks over them, such as
+	/// [`SliceSink`], write nothing if the codepoint doesn't fit, since a partial
+	/// codepoint isn't valid UTF-8.
+	///
+	/// [`SliceSink`]: crate::SliceSink
+	fn write_char(&mut self, value: char) -> Result<usize> {
 		let mut buf = [0; 4];
-		self.write_utf8(value.encode_utf8(&mut buf))
+		let str = value.encode_utf8(&mut buf);
+		self.write_utf8(str)?;
+		Ok(str.len())
+	}
+	/// Writes a single UTF-8 codepoint. Equivalent to [`write_char`], ignoring the
+	/// width.
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes. Like [`write_char`], mutable slices write nothing if the
+	/// codepoint doesn't fit.
+	///
+	/// [`write_char`]: Self::write_char
+	fn write_utf8_codepoint(&mut self, value: char) -> Result {
+		self.write_char(value).map(drop)
 	}
 	/// Writes an ASCII slice.
 	///
@@ -55,6 +352,25 @@ pub trait DataSink {
 	fn write_ascii(&mut self, value: &[ascii::Char]) -> Result {
 		self.write_bytes(value.as_bytes())
 	}
+	/// Writes bytes already known to be ASCII. Like [`write_ascii`], but takes a byte
+	/// slice, so is available without the `unstable_ascii_char` feature. The bytes
+	/// are only checked in debug builds.
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `bytes` contains a non-ASCII byte.
+	///
+	/// [`write_ascii`]: Self::write_ascii
+	fn write_ascii_bytes(&mut self, bytes: &[u8]) -> Result {
+		debug_assert!(bytes.is_ascii(), "bytes should be valid ASCII");
+		self.write_bytes(bytes)
+	}
 
 	/// Writes a [`u8`].
 	///
@@ -72,6 +388,32 @@ pub trait DataSink {
 	/// storage limit. In the case, the stream is filled completely, excluding the
 	/// overflowing bytes.
 	fn write_i8(&mut self, value: i8) -> Result { self.write_data(value) }
+	/// Writes all [`u8`]s from a slice. Equivalent to [`write_bytes`].
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes.
+	///
+	/// [`write_bytes`]: Self::write_bytes
+	fn write_u8_slice(&mut self, values: &[u8]) -> Result { self.write_bytes(values) }
+	/// Writes all [`i8`]s from a slice.
+	///
+	/// ```
+	/// # use data_streams::DataSink;
+	/// let mut sink = Vec::new();
+	/// sink.write_i8_slice(&[127, -128, -1])?;
+	/// assert_eq!(sink, [0x7F, 0x80, 0xFF]);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes.
+	fn write_i8_slice(&mut self, values: &[i8]) -> Result { self.write_bytes(cast_slice(values)) }
 	/// Writes a big-endian [`u16`].
 	///
 	/// # Errors
@@ -244,6 +586,157 @@ pub trait DataSink {
 	fn write_isize_le(&mut self, value: isize) -> Result {
 		self.write_i64_le(value as i64)
 	}
+	/// Writes a big-endian [`usize`] encoded in `bytes` bytes, for formats which
+	/// encode lengths in less than [`write_usize`]'s fixed 8 bytes.
+	///
+	/// ```
+	/// # use data_streams::DataSink;
+	/// let mut sink = Vec::new();
+	/// sink.write_usize_sized(0x0102, 2)?;
+	/// assert_eq!(sink, [0x01, 0x02]);
+	/// assert!(sink.write_usize_sized(0x1_0000, 2).is_err());
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::OutOfRange`] without writing if `value` doesn't fit in
+	/// `bytes` bytes. May return [`Overflow`](Error::Overflow) if the sink would
+	/// exceed some hard storage limit. In the case, the stream is filled completely,
+	/// excluding the overflowing bytes.
+	///
+	/// # Panics
+	///
+	/// Panics if `bytes` is not in range `1..=8`.
+	///
+	/// [`write_usize`]: Self::write_usize
+	fn write_usize_sized(&mut self, value: usize, bytes: usize) -> Result {
+		let value = check_usize_width(value, bytes)?;
+		self.write_bytes(&value.to_be_bytes()[8 - bytes..])
+	}
+	/// Writes a little-endian [`usize`] encoded in `bytes` bytes, for formats which
+	/// encode lengths in less than [`write_usize_le`]'s fixed 8 bytes.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::OutOfRange`] without writing if `value` doesn't fit in
+	/// `bytes` bytes. May return [`Overflow`](Error::Overflow) if the sink would
+	/// exceed some hard storage limit. In the case, the stream is filled completely,
+	/// excluding the overflowing bytes.
+	///
+	/// # Panics
+	///
+	/// Panics if `bytes` is not in range `1..=8`.
+	///
+	/// [`write_usize_le`]: Self::write_usize_le
+	fn write_usize_sized_le(&mut self, value: usize, bytes: usize) -> Result {
+		let value = check_usize_width(value, bytes)?;
+		self.write_bytes(&value.to_le_bytes()[..bytes])
+	}
+	/// Writes a big-endian Q7.8 fixed-point number, a signed [`i16`] with `8`
+	/// fractional bits. The value is rounded to the nearest representable number,
+	/// with ties rounded away from zero, and saturates if out of range.
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes.
+	fn write_fixed_i16_q8(&mut self, value: f32) -> Result {
+		self.write_i16(float_to_fixed(f64::from(value), 8))
+	}
+	/// Writes an integer as ASCII decimal text, such as for CSV or other text
+	/// formats. Unlike [`GenericDataSink::write_int`], which writes the binary
+	/// representation, this writes the digits as [`Display`] formats them, with a
+	/// leading `-` for negative values.
+	///
+	/// ```
+	/// # use data_streams::DataSink;
+	/// let mut sink = Vec::new();
+	/// sink.write_int_str(-42)?;
+	/// sink.write_u8(b',')?;
+	/// sink.write_int_str(u64::MAX)?;
+	/// assert_eq!(sink, b"-42,18446744073709551615");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes.
+	fn write_int_str<T: PrimInt + Display>(&mut self, value: T) -> Result where Self: Sized {
+		// The text is formatted on the stack, then written in one go.
+		let mut buf = DecimalBuf::new();
+		write!(buf, "{value}").expect("integer should fit in the decimal buffer");
+		self.write_bytes(buf.as_bytes())
+	}
+	/// Writes a number from its byte representation in the given byte order, as
+	/// returned by [`ToBytes`]. Unlike [`GenericDataSink::write_int`], this doesn't
+	/// require [`Pod`] or [`PrimInt`], so integer-like types such as newtypes or
+	/// big integers can be written if they implement [`ToBytes`].
+	///
+	/// ```
+	/// # use data_streams::{DataSink, Endian};
+	/// let mut sink = Vec::new();
+	/// sink.write_num(&0x0102u16, Endian::Big)?;
+	/// sink.write_num(&1.0f32, Endian::Little)?;
+	/// assert_eq!(sink, [1, 2, 0, 0, 0x80, 0x3F]);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes.
+	fn write_num<T: ToBytes + ?Sized>(&mut self, value: &T, endian: Endian) -> Result where Self: Sized {
+		match endian {
+			Endian::Big => self.write_bytes(value.to_be_bytes().as_ref()),
+			Endian::Little => self.write_bytes(value.to_le_bytes().as_ref()),
+		}
+	}
+
+	/// Copies all bytes from a [`Read`](std::io::Read) implementation into the
+	/// sink until it reaches its end, returning the number of bytes copied. This
+	/// allows splicing a file or socket into a sink without wrapping it as a
+	/// [`DataSource`](crate::DataSource) first.
+	///
+	/// ```
+	/// # use data_streams::DataSink;
+	/// let mut sink = Vec::new();
+	/// let count = sink.write_reader(&mut &b"Hello, World!"[..])?;
+	/// assert_eq!(count, 13);
+	/// assert_eq!(sink, b"Hello, World!");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered while reading as [`Error::Io`].
+	/// Interrupted reads are retried. May return [`Overflow`](Error::Overflow) if
+	/// the sink would exceed some hard storage limit. In the case, the stream is
+	/// filled completely, excluding the overflowing bytes.
+	#[cfg(feature = "std")]
+	fn write_reader(&mut self, reader: &mut (impl std::io::Read + ?Sized)) -> Result<u64> where Self: Sized {
+		let mut buf = [0; 8 * 1024];
+		let mut count = 0;
+		loop {
+			let len = match reader.read(&mut buf) {
+				Ok(0) => break Ok(count),
+				Ok(len) => len,
+				Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+				Err(error) => break Err(error.into())
+			};
+			self.write_bytes(&buf[..len])?;
+			count += len as u64;
+		}
+	}
+
+	/// Borrows the sink by mutable reference. Adapters which take the sink by value
+	/// can be given this reference instead, allowing writing to continue to the sink
+	/// after the adapter is done.
+	fn by_ref(&mut self) -> &mut Self where Self: Sized { self }
 }
 
 /// Writes generic data to a [sink](DataSink).
@@ -268,8 +761,99 @@ pub trait GenericDataSink<T: Pod>: DataSink {
 	fn write_int_le(&mut self, value: T) -> Result where T: PrimInt {
 		self.write_data(value.to_le())
 	}
+	/// Writes integers from an iterator in the given byte order. Unlike
+	/// [`write_data_array`], the integers don't need to be in a contiguous array,
+	/// so they can be computed lazily. Capacity is reserved for the lower bound of
+	/// the iterator's size hint.
+	///
+	/// ```
+	/// # use data_streams::{Endian, GenericDataSink};
+	/// let mut sink = Vec::new();
+	/// sink.write_ints((1..=3u16).map(|n| n * 0x101), Endian::Little)?;
+	/// assert_eq!(sink, [1, 1, 2, 2, 3, 3]);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, writing stops at the first integer that doesn't
+	/// fit; the stream is filled completely, excluding its overflowing bytes.
+	///
+	/// [`write_data_array`]: Self::write_data_array
+	fn write_ints(&mut self, values: impl IntoIterator<Item = T>, endian: Endian) -> Result
+	where
+		T: PrimInt,
+		Self: Sized
+	{
+		let values = values.into_iter();
+		self.reserve(values.size_hint().0.saturating_mul(size_of::<T>()))?;
+		for value in values {
+			self.write_data(endian.convert(value))?;
+		}
+		Ok(())
+	}
+	/// Writes a big-endian fixed-point number with `frac_bits` fractional bits. The
+	/// value is rounded to the nearest representable number, with ties rounded away
+	/// from zero. Out-of-range values saturate to the integer's minimum or maximum
+	/// rather than wrapping, and NaN is written as zero.
+	///
+	/// ```
+	/// # use data_streams::GenericDataSink;
+	/// let mut sink = Vec::new();
+	/// GenericDataSink::<i16>::write_fixed(&mut sink, 1.25, 8)?;
+	/// GenericDataSink::<i16>::write_fixed(&mut sink, 1000.0, 8)?;
+	/// assert_eq!(sink, [0x01, 0x40, 0x7F, 0xFF]);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes.
+	///
+	/// # Panics
+	///
+	/// Panics if `frac_bits` exceeds the bit width of `T`.
+	fn write_fixed(&mut self, value: f64, frac_bits: u32) -> Result where T: PrimInt {
+		self.write_int(float_to_fixed(value, frac_bits))
+	}
+	/// Writes a little-endian fixed-point number with `frac_bits` fractional bits,
+	/// rounding and saturating like [`write_fixed`](Self::write_fixed).
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes.
+	///
+	/// # Panics
+	///
+	/// Panics if `frac_bits` exceeds the bit width of `T`.
+	fn write_fixed_le(&mut self, value: f64, frac_bits: u32) -> Result where T: PrimInt {
+		self.write_int_le(float_to_fixed(value, frac_bits))
+	}
 	/// Writes a value of an arbitrary bit pattern. See [`Pod`].
 	///
+	/// The value's whole memory representation is written. [`Pod`] types must not
+	/// contain padding, which would otherwise be written as uninitialized bytes.
+	/// Deriving [`Pod`] checks this at compile time, rejecting structs like this:
+	///
+	/// ```ignore
+	/// #[derive(Copy, Clone, Pod, Zeroable)]
+	/// #[repr(C)]
+	/// struct Tag {
+	///     kind: u8,
+	///     // 3 padding bytes
+	///     len: u32,
+	/// }
+	/// ```
+	///
+	/// Make padding explicit with a field such as `_pad: [u8; 3]`, which is then
+	/// written with the value it holds. Implementing [`Pod`] manually for a type
+	/// with padding is unsound.
+	///
 	/// # Errors
 	///
 	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
@@ -278,6 +862,26 @@ pub trait GenericDataSink<T: Pod>: DataSink {
 	fn write_data(&mut self, value: T) -> Result {
 		self.write_bytes(bytes_of(&value))
 	}
+	/// Writes an array of `N` values of an arbitrary bit pattern, in one write. See
+	/// [`Pod`].
+	///
+	/// Values are written in their native memory representation; for integers this
+	/// is the platform's endianness. Convert each value with [`to_be`] or [`to_le`]
+	/// beforehand to write portable data. Like [`write_data`], `T` must not contain
+	/// padding.
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the sink would exceed some hard
+	/// storage limit. In the case, the stream is filled completely, excluding the
+	/// overflowing bytes.
+	///
+	/// [`to_be`]: PrimInt::to_be
+	/// [`to_le`]: PrimInt::to_le
+	/// [`write_data`]: Self::write_data
+	fn write_data_array<const N: usize>(&mut self, arr: &[T; N]) -> Result {
+		self.write_bytes(cast_slice(arr))
+	}
 }
 
 impl<S: DataSink + ?Sized, T: Pod> GenericDataSink<T> for S { }
@@ -341,3 +945,38 @@ impl<T: DataSink> VecSink for T {
 		self.write_utf8(&buf)
 	}
 }
+
+/// A stack buffer holding the decimal text of an integer.
+struct DecimalBuf {
+	// The longest text is i128::MIN, at 40 bytes.
+	buf: [u8; 40],
+	len: usize,
+}
+
+impl DecimalBuf {
+	fn new() -> Self {
+		Self { buf: [0; 40], len: 0 }
+	}
+
+	fn as_bytes(&self) -> &[u8] { &self.buf[..self.len] }
+}
+
+impl fmt::Write for DecimalBuf {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let spare = self.buf.get_mut(self.len..self.len + s.len()).ok_or(fmt::Error)?;
+		spare.copy_from_slice(s.as_bytes());
+		self.len += s.len();
+		Ok(())
+	}
+}
+
+/// Checks that `value` fits in `bytes` bytes, returning it widened to [`u64`].
+fn check_usize_width(value: usize, bytes: usize) -> Result<u64> {
+	assert!(matches!(bytes, 1..=8), "usize width must be in range 1..=8, got {bytes}");
+	let value = value as u64;
+	if bytes < 8 && value >> (bytes * 8) != 0 {
+		Err(Error::out_of_range(bytes))
+	} else {
+		Ok(value)
+	}
+}
@@ -0,0 +1,102 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use crate::{DataSink, Error, Result};
+
+/// A sink accumulating bytes into a vector up to a maximum length, overflowing
+/// past it rather than growing without bound. This protects against memory
+/// exhaustion when buffering untrusted data, such as a response body.
+///
+/// ```
+/// # use data_streams::{CappedVecSink, DataSink, Error};
+/// let mut sink = CappedVecSink::new(6);
+/// sink.write_u32(0xCAFE_BABE)?;
+/// assert_eq!(sink.spare(), 2);
+/// assert_eq!(sink.write_bytes(b"xyz"), Err(Error::overflow(1, 2)));
+/// assert_eq!(sink.into_inner(), [0xCA, 0xFE, 0xBA, 0xBE, b'x', b'y']);
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct CappedVecSink {
+	vec: Vec<u8>,
+	max: usize,
+}
+
+impl CappedVecSink {
+	/// Creates an empty sink accepting at most `max` bytes. Nothing is allocated
+	/// until bytes are written.
+	pub const fn new(max: usize) -> Self {
+		Self { vec: Vec::new(), max }
+	}
+
+	/// Returns the maximum number of bytes the sink accepts.
+	pub const fn max(&self) -> usize { self.max }
+
+	/// Returns the number of bytes which can be written before overflowing.
+	pub fn spare(&self) -> usize { self.max - self.vec.len() }
+
+	/// Returns a reference to the written bytes.
+	pub const fn get_ref(&self) -> &Vec<u8> { &self.vec }
+
+	/// Consumes the sink, returning the written bytes.
+	pub fn into_inner(self) -> Vec<u8> { self.vec }
+}
+
+impl DataSink for CappedVecSink {
+	/// Writes bytes from `buf` until the maximum length is reached.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Overflow`] if `buf` doesn't fit, after filling the sink up
+	/// to its maximum length. [`Error::Allocation`] is returned when capacity
+	/// cannot be allocated, leaving the sink unchanged.
+	fn write_bytes(&mut self, buf: &[u8]) -> Result {
+		let count = buf.len().min(self.spare());
+		self.vec.write_bytes(&buf[..count])?;
+		if count < buf.len() {
+			Err(Error::overflow(buf.len() - count, count))
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Reserves capacity for at least `additional` more bytes, up to the maximum
+	/// length.
+	///
+	/// # Errors
+	///
+	/// [`Error::Allocation`] is returned when capacity cannot be allocated.
+	fn reserve(&mut self, additional: usize) -> Result {
+		self.vec.try_reserve(additional.min(self.spare()))?;
+		Ok(())
+	}
+
+	/// Reserves capacity for exactly `additional` more bytes, up to the maximum
+	/// length.
+	///
+	/// # Errors
+	///
+	/// [`Error::Allocation`] is returned when capacity cannot be allocated.
+	fn reserve_exact(&mut self, additional: usize) -> Result {
+		self.vec.try_reserve_exact(additional.min(self.spare()))?;
+		Ok(())
+	}
+
+	/// Writes a single UTF-8 codepoint, returning its encoded width in bytes.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Overflow`] without writing if the codepoint doesn't fit,
+	/// rather than writing a partial, invalid codepoint. [`Error::Allocation`] is
+	/// returned when capacity cannot be allocated.
+	fn write_char(&mut self, value: char) -> Result<usize> {
+		let width = value.len_utf8();
+		if width > self.spare() {
+			return Err(Error::overflow(width, 0))
+		}
+		self.vec.write_char(value)
+	}
+}
@@ -0,0 +1,136 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{DataSink, Error, Result};
+
+/// A sink adapter counting the bytes written to an inner sink. Since sinks don't
+/// track their position in general, this allows padding fixed-length records and
+/// fields with [`write_padding_to`].
+///
+/// ```
+/// # use data_streams::{CountingSink, DataSink};
+/// let mut sink = CountingSink::new(Vec::new());
+/// sink.write_utf8("name")?;
+/// sink.write_padding_to(8, 0)?;
+/// sink.write_u16(0xCAFE)?;
+/// assert_eq!(sink.count(), 10);
+/// assert_eq!(sink.into_inner(), b"name\0\0\0\0\xCA\xFE");
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// [`write_padding_to`]: Self::write_padding_to
+#[derive(Clone, Debug, Default)]
+pub struct CountingSink<S> {
+	sink: S,
+	count: u64,
+}
+
+impl<S> CountingSink<S> {
+	/// Creates a counting adapter over `sink`, starting from zero.
+	pub const fn new(sink: S) -> Self {
+		Self { sink, count: 0 }
+	}
+
+	/// Returns the number of bytes written.
+	pub const fn count(&self) -> u64 { self.count }
+
+	/// Returns a reference to the inner sink.
+	pub const fn get_ref(&self) -> &S { &self.sink }
+
+	/// Returns a mutable reference to the inner sink. Bytes written to it directly
+	/// aren't counted.
+	pub fn get_mut(&mut self) -> &mut S { &mut self.sink }
+
+	/// Consumes the adapter, returning the inner sink.
+	pub fn into_inner(self) -> S { self.sink }
+}
+
+impl<S: DataSink> CountingSink<S> {
+	/// Writes `fill` bytes until [`count`] reaches `target_len`. Nothing is written
+	/// if the count is already `target_len`.
+	///
+	/// This is only available on a counting sink; other sinks don't track their
+	/// position, so wrap them in this adapter before writing the record to pad.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Overflow`] without writing if more than `target_len` bytes
+	/// were already written, with the excess byte count as its remaining count. The
+	/// inner sink may also return [`Overflow`](Error::Overflow) if it reaches a hard
+	/// storage limit while padding.
+	///
+	/// [`count`]: Self::count
+	pub fn write_padding_to(&mut self, target_len: u64, fill: u8) -> Result {
+		if self.count > target_len {
+			let excess = usize::try_from(self.count - target_len).unwrap_or(usize::MAX);
+			return Err(Error::overflow(excess, 0))
+		}
+
+		let buf = [fill; 64];
+		while self.count < target_len {
+			let len = usize::try_from(target_len - self.count).map_or(buf.len(), |len| len.min(buf.len()));
+			self.write_bytes(&buf[..len])?;
+		}
+		Ok(())
+	}
+}
+
+impl<S: DataSink> CountingSink<S> {
+	/// Adds the bytes written by a write of `len` bytes to the count.
+	fn count_written(&mut self, len: usize, result: &Result) {
+		self.count += match result {
+			Ok(()) => len,
+			Err(Error::Overflow { written, .. }) => *written,
+			Err(_) => 0
+		} as u64;
+	}
+}
+
+impl<S: DataSink> DataSink for CountingSink<S> {
+	fn write_bytes(&mut self, buf: &[u8]) -> Result {
+		let result = self.sink.write_bytes(buf);
+		self.count_written(buf.len(), &result);
+		result
+	}
+
+	// Text is forwarded rather than written as bytes, keeping the inner sink's
+	// handling of codepoints, such as mutable slices writing nothing when a
+	// codepoint doesn't fit.
+	fn write_utf8(&mut self, value: &str) -> Result {
+		let result = self.sink.write_utf8(value);
+		self.count_written(value.len(), &result);
+		result
+	}
+
+	fn write_char(&mut self, value: char) -> Result<usize> {
+		let result = self.sink.write_char(value);
+		match result {
+			Ok(width) => self.count += width as u64,
+			Err(Error::Overflow { written, .. }) => self.count += written as u64,
+			Err(_) => { }
+		}
+		result
+	}
+
+	fn try_write_bytes(&mut self, buf: &[u8]) -> Result<usize> {
+		let result = self.sink.try_write_bytes(buf);
+		match result {
+			Ok(count) => self.count += count as u64,
+			Err(Error::Overflow { written, .. }) => self.count += written as u64,
+			Err(_) => { }
+		}
+		result
+	}
+
+	fn reserve(&mut self, additional: usize) -> Result {
+		self.sink.reserve(additional)
+	}
+
+	fn reserve_exact(&mut self, additional: usize) -> Result {
+		self.sink.reserve_exact(additional)
+	}
+
+	fn flush(&mut self) -> Result {
+		self.sink.flush()
+	}
+}
@@ -0,0 +1,127 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use crate::DataSink;
+use crate::Result;
+
+/// A sink of discrete messages, such as a UDP socket or a message queue. Each call
+/// to [`write_message`] sends exactly one message.
+///
+/// Unlike a [`DataSink`], which is a byte stream where the bytes of consecutive
+/// writes run together, a message sink keeps the boundary of each write. Code
+/// assembling a frame from several `write_*` calls would send each piece as its
+/// own message over a datagram transport. To build a message with the
+/// [`DataSink`] methods, write it to a [`BufferedDatagramSink`], then flush it as
+/// one message.
+///
+/// [`write_message`]: Self::write_message
+/// [`DataSink`]: crate::DataSink
+pub trait DatagramSink {
+	/// Sends all bytes from `buf` as one message.
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered, such as the message exceeding the
+	/// transport's maximum size. Returns [`Overflow`](crate::Error::Overflow) if
+	/// only part of the message was sent.
+	fn write_message(&mut self, buf: &[u8]) -> Result;
+}
+
+impl<D: DatagramSink + ?Sized> DatagramSink for &mut D {
+	fn write_message(&mut self, buf: &[u8]) -> Result {
+		(**self).write_message(buf)
+	}
+}
+
+/// A [`DataSink`] accumulating written bytes, then sending them to a
+/// [message sink](DatagramSink) as one message when [flushed](DataSink::flush).
+///
+/// ```
+/// # use data_streams::{BufferedDatagramSink, DatagramSink, DataSink};
+/// // Collects each message separately.
+/// struct Messages(Vec<Vec<u8>>);
+///
+/// impl DatagramSink for Messages {
+///     fn write_message(&mut self, buf: &[u8]) -> data_streams::Result {
+///         self.0.push(buf.to_vec());
+///         Ok(())
+///     }
+/// }
+///
+/// let mut sink = BufferedDatagramSink::new(Messages(Vec::new()));
+/// sink.write_u16(0xCAFE)?;
+/// sink.write_utf8("hi")?;
+/// sink.flush()?;
+/// sink.write_u8(1)?;
+/// sink.flush()?;
+/// assert_eq!(sink.get_ref().0, [b"\xCA\xFEhi".to_vec(), vec![1]]);
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// Unflushed bytes are discarded when the sink is dropped, rather than sent as a
+/// possibly incomplete message.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct BufferedDatagramSink<D> {
+	sink: D,
+	buf: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl<D> BufferedDatagramSink<D> {
+	/// Creates a buffered sink over `sink`.
+	pub const fn new(sink: D) -> Self {
+		Self { sink, buf: Vec::new() }
+	}
+
+	/// Creates a buffered sink over `sink`, with capacity for a message of at
+	/// least `capacity` bytes.
+	pub fn with_capacity(sink: D, capacity: usize) -> Self {
+		Self { sink, buf: Vec::with_capacity(capacity) }
+	}
+
+	/// Returns the bytes written since the last message was sent.
+	pub fn buffer(&self) -> &[u8] { &self.buf }
+
+	/// Returns a reference to the inner sink.
+	pub const fn get_ref(&self) -> &D { &self.sink }
+
+	/// Returns a mutable reference to the inner sink.
+	pub fn get_mut(&mut self) -> &mut D { &mut self.sink }
+
+	/// Consumes the buffered sink, returning the inner sink. Unflushed bytes are
+	/// discarded.
+	pub fn into_inner(self) -> D { self.sink }
+}
+
+#[cfg(feature = "alloc")]
+impl<D: DatagramSink> DataSink for BufferedDatagramSink<D> {
+	fn write_bytes(&mut self, buf: &[u8]) -> Result {
+		self.buf.try_reserve(buf.len())?;
+		self.buf.extend_from_slice(buf);
+		Ok(())
+	}
+
+	fn reserve(&mut self, additional: usize) -> Result {
+		self.buf.try_reserve(additional)?;
+		Ok(())
+	}
+
+	fn reserve_exact(&mut self, additional: usize) -> Result {
+		self.buf.try_reserve_exact(additional)?;
+		Ok(())
+	}
+
+	/// Sends the written bytes as one message, if any were written. The buffer is
+	/// cleared once sent, and kept if sending fails.
+	fn flush(&mut self) -> Result {
+		if !self.buf.is_empty() {
+			self.sink.write_message(&self.buf)?;
+			self.buf.clear();
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,183 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{DataSink, Error, Result};
+
+/// A byte-stuffing scheme, escaping a frame delimiter byte in a payload so that the
+/// delimiter only appears between frames. In an escaped payload, the delimiter is
+/// written as the escape byte followed by a substitute, and the escape byte itself
+/// is written as the escape byte followed by its own substitute.
+///
+/// Escaping at most doubles the payload length, when every byte is either the
+/// delimiter or the escape byte.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Escape {
+	prefix: u8,
+	delimiter: u8,
+	delimiter_code: u8,
+	prefix_code: u8,
+}
+
+impl Escape {
+	/// The escaping used by [SLIP](https://datatracker.ietf.org/doc/html/rfc1055):
+	/// `C0` delimits frames, written as `DB DC`, and the escape byte `DB` is written
+	/// as `DB DD`.
+	pub const SLIP: Self = Self::new(0xDB, 0xC0, 0xDC).with_escaped_escape(0xDD);
+
+	/// Creates a scheme writing `delimiter` as `escape escaped_delimiter`, and
+	/// `escape` as `escape escape`.
+	///
+	/// # Panics
+	///
+	/// Panics if `escape` and `delimiter` are the same byte, or if the delimiter
+	/// substitute is either of them, as escaped bytes couldn't be told apart.
+	pub const fn new(escape: u8, delimiter: u8, escaped_delimiter: u8) -> Self {
+		assert!(escape != delimiter, "the escape and delimiter bytes must differ");
+		assert!(
+			escaped_delimiter != escape && escaped_delimiter != delimiter,
+			"the delimiter substitute must differ from the escape and delimiter bytes"
+		);
+		Self { prefix: escape, delimiter, delimiter_code: escaped_delimiter, prefix_code: escape }
+	}
+
+	/// Writes the escape byte as `escape escaped_escape` instead of repeating it.
+	///
+	/// # Panics
+	///
+	/// Panics if `escaped_escape` is the delimiter or its substitute.
+	#[must_use]
+	pub const fn with_escaped_escape(mut self, escaped_escape: u8) -> Self {
+		assert!(
+			escaped_escape != self.delimiter && escaped_escape != self.delimiter_code,
+			"the escape substitute must differ from the delimiter and its substitute"
+		);
+		self.prefix_code = escaped_escape;
+		self
+	}
+
+	/// Returns the escape byte.
+	pub const fn escape(self) -> u8 { self.prefix }
+
+	/// Returns the frame delimiter byte.
+	pub const fn delimiter(self) -> u8 { self.delimiter }
+
+	/// Returns the substitute for `byte` following an escape byte, or `None` if
+	/// `byte` is written unchanged.
+	pub(crate) const fn substitute(self, byte: u8) -> Option<u8> {
+		if byte == self.delimiter {
+			Some(self.delimiter_code)
+		} else if byte == self.prefix {
+			Some(self.prefix_code)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the original byte for a substitute following an escape byte. Unknown
+	/// substitutes are returned unchanged.
+	pub(crate) const fn unsubstitute(self, byte: u8) -> u8 {
+		if byte == self.delimiter_code {
+			self.delimiter
+		} else if byte == self.prefix_code {
+			self.prefix
+		} else {
+			byte
+		}
+	}
+}
+
+/// A sink adapter escaping the bytes written to it, for framing protocols such as
+/// SLIP. Payload bytes are escaped with an [`Escape`] scheme, and frames are ended
+/// with [`write_delimiter`]. Read the frames back with [`UnescapingSource`].
+///
+/// Since escaping can double the payload length, fixed-size sinks may overflow
+/// sooner than the unescaped length suggests.
+///
+/// ```
+/// # use data_streams::{DataSink, Escape, EscapingSink};
+/// let mut sink = EscapingSink::new(Vec::new(), Escape::SLIP);
+/// sink.write_bytes(&[1, 0xC0, 2, 0xDB])?;
+/// sink.write_delimiter()?;
+/// assert_eq!(sink.into_inner(), [1, 0xDB, 0xDC, 2, 0xDB, 0xDD, 0xC0]);
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// [`write_delimiter`]: Self::write_delimiter
+/// [`UnescapingSource`]: crate::UnescapingSource
+#[derive(Clone, Debug)]
+pub struct EscapingSink<S> {
+	sink: S,
+	escape: Escape,
+}
+
+impl<S> EscapingSink<S> {
+	/// Creates an adapter escaping bytes written to `sink`.
+	pub const fn new(sink: S, escape: Escape) -> Self {
+		Self { sink, escape }
+	}
+
+	/// Returns the escaping scheme.
+	pub const fn escape(&self) -> Escape { self.escape }
+
+	/// Returns a reference to the inner sink.
+	pub const fn get_ref(&self) -> &S { &self.sink }
+
+	/// Returns a mutable reference to the inner sink. Bytes written to it directly
+	/// are not escaped.
+	pub fn get_mut(&mut self) -> &mut S { &mut self.sink }
+
+	/// Consumes the adapter, returning the inner sink.
+	pub fn into_inner(self) -> S { self.sink }
+}
+
+impl<S: DataSink> EscapingSink<S> {
+	/// Writes an unescaped delimiter, ending the current frame.
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the inner sink is full.
+	pub fn write_delimiter(&mut self) -> Result {
+		self.sink.write_u8(self.escape.delimiter)
+	}
+}
+
+impl<S: DataSink> DataSink for EscapingSink<S> {
+	/// Writes all bytes from `buf`, escaping them.
+	///
+	/// # Errors
+	///
+	/// May return [`Overflow`](Error::Overflow) if the inner sink would exceed some
+	/// hard storage limit. The remaining and written counts are in bytes of `buf`,
+	/// not escaped bytes. If the sink fills between an escape byte and its
+	/// substitute, the escape byte is written but not counted.
+	fn write_bytes(&mut self, buf: &[u8]) -> Result {
+		let mut start = 0;
+		for (i, &byte) in buf.iter().enumerate() {
+			let Some(substitute) = self.escape.substitute(byte) else { continue };
+			write_run(&mut self.sink, buf, start, i)?;
+			match self.sink.write_bytes(&[self.escape.prefix, substitute]) {
+				Ok(()) => { }
+				Err(Error::Overflow { .. }) => return Err(Error::overflow(buf.len() - i, i)),
+				Err(error) => return Err(error)
+			}
+			start = i + 1;
+		}
+		write_run(&mut self.sink, buf, start, buf.len())
+	}
+
+	fn flush(&mut self) -> Result {
+		self.sink.flush()
+	}
+}
+
+/// Writes the unescaped run of bytes between `start` and `end`, converting overflow
+/// counts into counts of `buf`.
+fn write_run(sink: &mut impl DataSink, buf: &[u8], start: usize, end: usize) -> Result {
+	match sink.write_bytes(&buf[start..end]) {
+		Err(Error::Overflow { written, .. }) => {
+			let written = start + written;
+			Err(Error::overflow(buf.len() - written, written))
+		}
+		result => result
+	}
+}
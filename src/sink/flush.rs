@@ -0,0 +1,53 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use core::mem;
+use core::ops::{Deref, DerefMut};
+use crate::{DataSink, Result};
+
+/// A guard which flushes a [sink](DataSink) when dropped, returned by
+/// [`DataSink::flush_guard`]. It dereferences to the sink, so writes can be made
+/// through it.
+///
+/// This is a convenience, not a substitute for handling the flush error: errors
+/// from a flush on drop are ignored. Use [`finish`] to flush and get the result.
+///
+/// [`finish`]: Self::finish
+#[must_use = "the sink is flushed when the guard is dropped"]
+pub struct FlushGuard<'a, S: DataSink + ?Sized> {
+	sink: &'a mut S,
+}
+
+impl<'a, S: DataSink + ?Sized> FlushGuard<'a, S> {
+	/// Creates a new guard, flushing `sink` when dropped.
+	pub fn new(sink: &'a mut S) -> Self {
+		Self { sink }
+	}
+
+	/// Flushes the sink, returning the result.
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered.
+	pub fn finish(self) -> Result {
+		let result = self.sink.flush();
+		mem::forget(self);
+		result
+	}
+}
+
+impl<S: DataSink + ?Sized> Deref for FlushGuard<'_, S> {
+	type Target = S;
+
+	fn deref(&self) -> &S { self.sink }
+}
+
+impl<S: DataSink + ?Sized> DerefMut for FlushGuard<'_, S> {
+	fn deref_mut(&mut self) -> &mut S { self.sink }
+}
+
+impl<S: DataSink + ?Sized> Drop for FlushGuard<'_, S> {
+	fn drop(&mut self) {
+		let _ = self.sink.flush();
+	}
+}
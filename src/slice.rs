@@ -4,22 +4,53 @@
 use core::mem::take;
 use crate::{DataSink, Error, Result};
 
+// Writes integers directly into the slice, skipping the generic write_data path.
+macro_rules! write_int {
+    ($($name:ident($ty:ty) => $to_bytes:ident;)+) => {
+		$(
+		fn $name(&mut self, value: $ty) -> Result {
+			mut_slice_write_array(self, value.$to_bytes())
+		}
+		)+
+	};
+}
+
 impl DataSink for &mut [u8] {
 	fn write_bytes(&mut self, buf: &[u8]) -> Result {
 		mut_slice_write_bytes(self, buf, <[u8]>::copy_from_slice)
 	}
 
-	fn write_utf8_codepoint(&mut self, value: char) -> Result {
-		if let Some((buf, remaining)) = take(self).split_at_mut_checked(value.len_utf8()) {
-			// Encode directly into the sink slice.
-			value.encode_utf8(buf);
-			*self = remaining;
-			Ok(())
-		} else {
-			// Encode into an intermediate buffer, then write until overflow.
-			let mut buf = [0; 4];
-			self.write_utf8(value.encode_utf8(&mut buf))
+	write_int! {
+		write_u16(u16) => to_be_bytes;
+		write_i16(i16) => to_be_bytes;
+		write_u16_le(u16) => to_le_bytes;
+		write_i16_le(i16) => to_le_bytes;
+		write_u32(u32) => to_be_bytes;
+		write_i32(i32) => to_be_bytes;
+		write_u32_le(u32) => to_le_bytes;
+		write_i32_le(i32) => to_le_bytes;
+		write_u64(u64) => to_be_bytes;
+		write_i64(i64) => to_be_bytes;
+		write_u64_le(u64) => to_le_bytes;
+		write_i64_le(i64) => to_le_bytes;
+		write_u128(u128) => to_be_bytes;
+		write_i128(i128) => to_be_bytes;
+		write_u128_le(u128) => to_le_bytes;
+		write_i128_le(i128) => to_le_bytes;
+	}
+
+	fn write_char(&mut self, value: char) -> Result<usize> {
+		let width = value.len_utf8();
+		if width > self.len() {
+			// Write nothing rather than a partial, invalid codepoint.
+			return Err(Error::overflow(width, 0))
 		}
+
+		// Encode directly into the sink slice.
+		let (buf, remaining) = take(self).split_at_mut(width);
+		value.encode_utf8(buf);
+		*self = remaining;
+		Ok(width)
 	}
 
 	fn write_u8(&mut self, value: u8) -> Result {
@@ -32,6 +63,78 @@ impl DataSink for &mut [u8] {
 	}
 }
 
+/// A sink writing sequentially into fixed, owned or borrowed mutable storage,
+/// such as a memory-mapped file (`memmap2::MmapMut`) or an array. Like a mutable
+/// slice, it overflows once full, but tracks its position in the storage rather
+/// than shrinking. For a mutable slice itself, writing to `&mut [u8]` directly is
+/// simpler.
+///
+/// To read from read-only storage such as `memmap2::Mmap`, use a [`Cursor`] over
+/// it, which reads directly from the storage and has exact
+/// [size bounds](crate::markers::source::SourceSize).
+///
+/// ```
+/// # use data_streams::{DataSink, Error, SliceSink};
+/// let mut sink = SliceSink::new([0; 6]);
+/// sink.write_u32(0xCAFE_BABE)?;
+/// assert_eq!(sink.position(), 4);
+/// assert_eq!(sink.write_bytes(b"xyz"), Err(Error::overflow(1, 2)));
+/// assert_eq!(sink.into_inner(), [0xCA, 0xFE, 0xBA, 0xBE, b'x', b'y']);
+/// # Ok::<_, Error>(())
+/// ```
+///
+/// [`Cursor`]: std::io::Cursor
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct SliceSink<T> {
+	storage: T,
+	position: usize,
+}
+
+impl<T> SliceSink<T> {
+	/// Creates a sink writing from the start of `storage`, overwriting its
+	/// contents.
+	pub const fn new(storage: T) -> Self {
+		Self { storage, position: 0 }
+	}
+
+	/// Returns the number of bytes written.
+	pub const fn position(&self) -> usize { self.position }
+
+	/// Returns a reference to the storage.
+	pub const fn get_ref(&self) -> &T { &self.storage }
+
+	/// Returns a mutable reference to the storage.
+	pub fn get_mut(&mut self) -> &mut T { &mut self.storage }
+
+	/// Consumes the sink, returning the storage.
+	pub fn into_inner(self) -> T { self.storage }
+}
+
+impl<T: AsRef<[u8]>> SliceSink<T> {
+	/// Returns the number of bytes which can be written before overflowing.
+	pub fn spare(&self) -> usize { self.storage.as_ref().len() - self.position }
+
+	/// Returns the written bytes.
+	pub fn filled(&self) -> &[u8] { &self.storage.as_ref()[..self.position] }
+}
+
+impl<T: AsMut<[u8]>> DataSink for SliceSink<T> {
+	fn write_bytes(&mut self, buf: &[u8]) -> Result {
+		let storage = self.storage.as_mut();
+		let len = storage.len();
+		let mut spare = &mut storage[self.position..];
+		let result = spare.write_bytes(buf);
+		self.position = len - spare.len();
+		result
+	}
+
+	fn write_char(&mut self, value: char) -> Result<usize> {
+		let width = (&mut self.storage.as_mut()[self.position..]).write_char(value)?;
+		self.position += width;
+		Ok(width)
+	}
+}
+
 #[cfg(feature = "unstable_uninit_slice")]
 use core::mem::MaybeUninit;
 
@@ -63,12 +166,24 @@ fn mut_slice_write_bytes<T>(
 	copy_from_slice(target, &buf[..len]);
 	let remaining = buf.len() - len;
 	if remaining > 0 {
-		Err(Error::overflow(remaining))
+		Err(Error::overflow(remaining, len))
 	} else {
 		Ok(())
 	}
 }
 
+#[allow(clippy::mut_mut)]
+fn mut_slice_write_array<const N: usize>(sink: &mut &mut [u8], bytes: [u8; N]) -> Result {
+	if sink.len() < N {
+		return mut_slice_write_bytes(sink, &bytes, <[u8]>::copy_from_slice)
+	}
+
+	let (target, remaining) = take(sink).split_at_mut(N);
+	target.copy_from_slice(&bytes);
+	*sink = remaining;
+	Ok(())
+}
+
 #[allow(clippy::mut_mut)]
 fn mut_slice_push_u8<T>(
 	sink: &mut &mut [T],
@@ -76,7 +191,7 @@ fn mut_slice_push_u8<T>(
 	map: impl FnOnce(u8) -> T
 ) -> Result {
 	if sink.is_empty() {
-		Err(Error::overflow(1))
+		Err(Error::overflow(1, 0))
 	} else {
 		sink[0] = map(value);
 		*sink = &mut take(sink)[1..];
@@ -4,17 +4,39 @@
 use bytemuck::{bytes_of_mut, cast_slice_mut, Pod};
 #[cfg(feature = "unstable_ascii_char")]
 use core::ascii;
-use bytemuck::cast_slice;
+#[cfg(feature = "unstable_borrowed_buf")]
+use core::io::BorrowedCursor;
+use core::mem::MaybeUninit;
+use bytemuck::{cast_slice, try_cast_slice};
 use num_traits::PrimInt;
 #[cfg(feature = "utf8")]
 use simdutf8::compat::from_utf8;
-use crate::{Error, Result};
+use crate::{DataSink, Error, Result};
+use crate::fixed::fixed_to_float;
+#[cfg(feature = "alloc")]
+use crate::markers::source::SourceSize;
 #[cfg(feature = "utf8")]
 use crate::utf8::utf8_char_width;
 
+mod chars;
+mod escape;
 mod exact_size;
 mod impls;
+mod iter;
 pub mod markers;
+mod pushback;
+mod verified;
+
+#[cfg(feature = "utf8")]
+pub use chars::Chars;
+pub use escape::UnescapingSource;
+pub use exact_size::{ArraySource, SliceSource, StrSource};
+pub use iter::IterSource;
+pub use pushback::PushbackSource;
+#[cfg(feature = "digest")]
+pub use verified::Verified;
+#[cfg(all(feature = "alloc", feature = "unstable_specialization"))]
+pub(crate) use impls::is_infinite;
 
 /// A source stream of data.
 pub trait DataSource {
@@ -22,6 +44,22 @@ pub trait DataSource {
 	/// mean more data isn't available, just that *at least* this count is may be
 	/// read.
 	fn available(&self) -> usize;
+	/// Returns the exact number of bytes remaining until the end of the source, if
+	/// known, or `None` otherwise. This is distinct from [`available`], which only
+	/// counts bytes that can be read right away, such as those currently buffered;
+	/// a source may have few bytes available but many more remaining.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let mut source: &[u8] = b"Hello, World!";
+	/// assert_eq!(source.remaining(), Some(13));
+	/// source.skip(7)?;
+	/// assert_eq!(source.remaining(), Some(6));
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// [`available`]: Self::available
+	fn remaining(&self) -> Option<u64> { None }
 	/// Reads at most `count` bytes into an internal buffer, returning whether
 	/// enough bytes are available. To return an end-of-stream error, use [`require`]
 	/// instead.
@@ -33,8 +71,11 @@ pub trait DataSource {
 	///
 	/// If the byte count exceeds the spare buffer capacity, [`Error::InsufficientBuffer`]
 	/// is returned and both the internal buffer and underlying streams remain unchanged.
+	/// Sources which know fewer than `count` bytes [remain] return `false` instead,
+	/// such as in-memory sources whose data is all buffered.
 	///
 	/// [`require`]: Self::require
+	/// [remain]: Self::remaining
 	fn request(&mut self, count: usize) -> Result<bool>;
 	/// Reads at least `count` bytes into an internal buffer, returning `Ok` if
 	/// successful, or an end-of-stream error if not. For a softer version that
@@ -43,24 +84,26 @@ pub trait DataSource {
 	/// # Errors
 	///
 	/// Returns [`Error::End`] if the stream ended before `count` bytes could be
-	/// read. If the byte count exceeds the spare buffer capacity, [`Error::InsufficientBuffer`]
-	/// is returned instead.
+	/// read. No bytes are consumed in this case. If the byte count exceeds the spare
+	/// buffer capacity, [`Error::InsufficientBuffer`] is returned instead.
 	///
 	/// [`request`]: Self::request
 	fn require(&mut self, count: usize) -> Result {
 		if self.request(count)? {
 			Ok(())
 		} else {
-			Err(Error::end(count))
+			Err(Error::end(count, 0))
 		}
 	}
 
 	/// Consumes up to `count` bytes in the stream, returning the number of bytes
-	/// consumed if successful. At least the available count may be consumed.
+	/// consumed if successful. At least the available count may be consumed. A
+	/// count less than `count` means the end of the stream was reached.
 	///
 	/// # Errors
 	///
-	/// Returns any IO errors encountered.
+	/// Returns any IO errors encountered. Errors aren't reported as a short count;
+	/// bytes skipped before the error remain consumed.
 	fn skip(&mut self, count: usize) -> Result<usize>;
 	/// Reads bytes into a slice, returning the bytes read. This method is greedy;
 	/// it consumes as many bytes as it can, until `buf` is filled or no more bytes
@@ -69,6 +112,12 @@ pub trait DataSource {
 	/// # Errors
 	///
 	/// Returns any IO errors encountered.
+	///
+	/// # Implementation
+	///
+	/// Implementations should be greedy, but exact reads call this method until
+	/// `buf` is filled or no bytes are read, so an implementation making a single
+	/// read from its stream is still correct.
 	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]>;
 	/// Reads the exact length of bytes into a slice, returning the bytes read if
 	/// successful, or an end-of-stream error if not. Bytes are not consumed if an
@@ -78,23 +127,191 @@ pub trait DataSource {
 	///
 	/// Returns [`Error::End`] with the slice length if the exact number of bytes
 	/// cannot be read. The bytes that were read remain in the buffer, but have
-	/// been consumed from the source.
+	/// been consumed from the source; their count is given by the error.
 	fn read_exact_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
 		default_read_exact_bytes(self, buf)
 	}
+	/// Reads the exact length of bytes into a slice like [`read_exact_bytes`],
+	/// calling `on_progress` with the total byte count read so far after each read.
+	/// Buffered sources are read one buffer fill at a time; others may be read in
+	/// a single call.
+	///
+	/// The callback can't abort the read. To implement a deadline, for example,
+	/// record the elapsed time in the callback and check it after returning.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let mut source: &[u8] = b"Hello, World!";
+	/// let mut progress = Vec::new();
+	/// let mut buf = [0; 5];
+	/// let bytes = source.read_exact_bytes_with(&mut buf, |count| progress.push(count))?;
+	/// assert_eq!(bytes, b"Hello");
+	/// assert_eq!(progress, [5]);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] with the slice length if the exact number of bytes
+	/// cannot be read. Unlike [`read_exact_bytes`], the bytes that were read have
+	/// been consumed from both the source and its buffer.
+	///
+	/// [`read_exact_bytes`]: Self::read_exact_bytes
+	fn read_exact_bytes_with<'a>(
+		&mut self,
+		buf: &'a mut [u8],
+		mut on_progress: impl FnMut(usize)
+	) -> Result<&'a [u8]>
+	where
+		Self: Sized
+	{
+		let len = buf.len();
+		let mut filled = 0;
+		while filled < len {
+			let remaining = len - filled;
+			// Request one byte to fill an empty buffer, then read what was buffered.
+			// Without a buffer, try to read the whole remainder at once.
+			let chunk = match self.request(1) {
+				Ok(_) if self.available() > 0 => remaining.min(self.available()),
+				Ok(_) | Err(Error::InsufficientBuffer { .. }) => remaining,
+				Err(error) => return Err(error)
+			};
+			let count = self.read_bytes(&mut buf[filled..filled + chunk])?.len();
+			if count == 0 {
+				return Err(Error::end(len, filled))
+			}
+			filled += count;
+			on_progress(filled);
+		}
+		Ok(buf)
+	}
+	/// Reads the exact length of bytes into a slice like [`read_exact_bytes`], but
+	/// resumes from `filled` bytes already read into `buf`, updating it as bytes
+	/// are read. When an error interrupts the read, such as [`Error::End`] or a
+	/// [`WouldBlock`] IO error from a non-blocking socket, the partial fill is kept
+	/// in `buf` and `filled`, so the caller can retry once the stream resumes.
+	///
+	/// ```
+	/// # use data_streams::{DataSource, Error};
+	/// let mut buf = [0; 8];
+	/// let mut filled = 0;
+	/// let mut source: &[u8] = b"Hello";
+	/// assert_eq!(source.read_exact_bytes_resumable(&mut buf, &mut filled), Err(Error::end(8, 5)));
+	/// // More data arrives.
+	/// source = b", World!";
+	/// assert_eq!(source.read_exact_bytes_resumable(&mut buf, &mut filled)?, b"Hello, W");
+	/// assert_eq!(filled, 8);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// Progress is recorded after each read, one buffer fill at a time for buffered
+	/// sources. Unbuffered sources may read the whole remainder in one call, which
+	/// loses the progress of that call if it's interrupted.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] with the slice length and total filled count if the
+	/// stream ends before `buf` is filled. Returns any IO errors encountered.
+	///
+	/// # Panics
+	///
+	/// Panics if `filled` exceeds the length of `buf`.
+	///
+	/// [`read_exact_bytes`]: Self::read_exact_bytes
+	/// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+	fn read_exact_bytes_resumable<'a>(&mut self, buf: &'a mut [u8], filled: &mut usize) -> Result<&'a [u8]>
+	where
+		Self: Sized
+	{
+		let start = *filled;
+		assert!(start <= buf.len(), "filled count exceeds the slice length");
+		match self.read_exact_bytes_with(&mut buf[start..], |count| *filled = start + count) {
+			Ok(_) => Ok(buf),
+			Err(Error::End { .. }) => Err(Error::end(buf.len(), *filled)),
+			Err(error) => Err(error)
+		}
+	}
 	/// Reads bytes into a slice in multiples of `alignment`, returning the bytes
 	/// read. This method is greedy; it consumes as many bytes as it can, until
 	/// `buf` is filled or less than `alignment` bytes could be read.
+	///
+	/// On sources which can [request] a whole value before reading it or know the
+	/// [remaining] byte count, such as slices and `Cursor`, a partial value at the
+	/// end of the stream is left unconsumed, and can still be read afterward.
+	/// Buffered readers which can only refill an empty buffer, like `BufReader`,
+	/// may consume a value split across the end of their buffer to read it,
+	/// dropping a partial value at the end of their stream.
 	/// 
 	/// If the alignment is zero, the returned slice is empty.
 	/// 
 	/// # Errors
 	/// 
 	/// Returns any IO errors encountered.
+	///
+	/// [request]: Self::request
+	/// [remaining]: Self::remaining
 	fn read_aligned_bytes<'a>(&mut self, buf: &'a mut [u8], alignment: usize) -> Result<&'a [u8]> {
 		default_read_aligned_bytes(self, buf, alignment)
 	}
-	/// Reads an array with a size of `N` bytes.
+	/// Reads bytes into the unfilled portion of a [`BorrowedCursor`], advancing its
+	/// filled region, and returns the number of bytes read. This method is greedy;
+	/// it consumes as many bytes as it can, until the cursor is filled or no more
+	/// bytes are read.
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered.
+	///
+	/// # Implementation
+	///
+	/// The default implementation initializes the cursor before reading into it
+	/// with [`read_bytes`]. Sources with an internal buffer copy directly into the
+	/// uninitialized memory instead.
+	///
+	/// # Panics
+	///
+	/// The default implementation panics if [`read_bytes`] returns a slice other
+	/// than the start of the cursor's unfilled portion.
+	///
+	/// [`read_bytes`]: Self::read_bytes
+	#[cfg(feature = "unstable_borrowed_buf")]
+	fn read_into_borrowed(&mut self, cursor: &mut BorrowedCursor<'_>) -> Result<usize> {
+		default_read_into_borrowed(self, cursor)
+	}
+	/// Reads bytes into uninitialized memory, returning the initialized bytes read.
+	/// This method is greedy like [`read_bytes`]; it consumes as many bytes as it
+	/// can, until `buf` is filled or no more bytes are read. Bytes past the returned
+	/// slice are left uninitialized. The returned slice must start at the start of
+	/// `buf`; callers initializing a vector's length from it check this, panicking
+	/// otherwise.
+	///
+	/// ```
+	/// # use std::mem::MaybeUninit;
+	/// # use data_streams::DataSource;
+	/// let mut source = &b"Hello"[..];
+	/// let mut buf = [MaybeUninit::uninit(); 8];
+	/// assert_eq!(source.read_into_uninit(&mut buf)?, b"Hello");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered.
+	///
+	/// # Implementation
+	///
+	/// With the `unstable_borrowed_buf` feature, the default implementation reads
+	/// with [`read_into_borrowed`], which copies directly into the uninitialized
+	/// memory for buffered sources. Otherwise, `buf` is zeroed before reading into
+	/// it with [`read_bytes`].
+	///
+	/// [`read_bytes`]: Self::read_bytes
+	/// [`read_into_borrowed`]: Self::read_into_borrowed
+	fn read_into_uninit<'a>(&mut self, buf: &'a mut [MaybeUninit<u8>]) -> Result<&'a [u8]> {
+		default_read_into_uninit(self, buf)
+	}
+	/// Reads an array with a size of `N` bytes. To read from a trait object, use the
+	/// [`read_array`](crate::read_array) function instead.
 	///
 	/// # Errors
 	///
@@ -105,6 +322,30 @@ pub trait DataSource {
 	{
 		default_read_array(self)
 	}
+	/// Reads `N` bytes and checks they match `expected`, such as a file format's
+	/// magic number.
+	///
+	/// ```
+	/// # use data_streams::{DataSource, Error};
+	/// let mut source = &b"\x89PNG\r\n\x1A\n"[..];
+	/// source.read_magic(b"\x89PNG")?;
+	/// assert_eq!(source.read_magic(b"\0\0"), Err(Error::mismatch(b"\0\0", b"\r\n")));
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Mismatch`] with the bytes read if they don't match. The bytes
+	/// are consumed in this case. Returns [`Error::End`] if the stream ends before
+	/// `N` bytes can be read.
+	fn read_magic<const N: usize>(&mut self, expected: &[u8; N]) -> Result where Self: Sized {
+		let found = self.read_array::<N>()?;
+		if &found == expected {
+			Ok(())
+		} else {
+			Err(Error::mismatch(expected, &found))
+		}
+	}
 
 	/// Reads a [`u8`].
 	///
@@ -120,6 +361,36 @@ pub trait DataSource {
 	/// Returns [`Error::End`] if the stream ends before exactly `1` byte can be
 	/// read.
 	fn read_i8(&mut self) -> Result<i8> { self.read_data() }
+	/// Reads [`u8`]s into a slice, returning the values read. Equivalent to
+	/// [`read_bytes`].
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered.
+	///
+	/// [`read_bytes`]: Self::read_bytes
+	fn read_u8_slice<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		self.read_bytes(buf)
+	}
+	/// Reads [`i8`]s into a slice, returning the values read. Like [`read_bytes`],
+	/// this method is greedy.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let mut source: &[u8] = &[0x7F, 0x80, 0xFF];
+	/// assert_eq!(source.read_i8_slice(&mut [0; 4])?, [127, -128, -1]);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered.
+	///
+	/// [`read_bytes`]: Self::read_bytes
+	fn read_i8_slice<'a>(&mut self, buf: &'a mut [i8]) -> Result<&'a [i8]> {
+		let count = self.read_bytes(cast_slice_mut(buf))?.len();
+		Ok(&buf[..count])
+	}
 	/// Reads a big-endian [`u16`].
 	///
 	/// # Errors
@@ -272,6 +543,102 @@ pub trait DataSource {
 	fn read_isize_le(&mut self) -> Result<isize> {
 		self.read_i64_le().map(|i| i as isize)
 	}
+	/// Reads a big-endian [`usize`] encoded in `bytes` bytes, for formats which
+	/// encode lengths in less than [`read_usize`]'s fixed 8 bytes.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let mut source: &[u8] = &[0x01, 0x02, 0x03, 0x04, 0x05];
+	/// assert_eq!(source.read_usize_sized(2)?, 0x0102);
+	/// assert_eq!(source.read_usize_sized(3)?, 0x030405);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before exactly `bytes` bytes can
+	/// be read. Returns [`Error::OutOfRange`] if the value read doesn't fit in the
+	/// target's [`usize`].
+	///
+	/// # Panics
+	///
+	/// Panics if `bytes` is not in range `1..=8`.
+	///
+	/// [`read_usize`]: Self::read_usize
+	fn read_usize_sized(&mut self, bytes: usize) -> Result<usize> {
+		assert!(matches!(bytes, 1..=8), "usize width must be in range 1..=8, got {bytes}");
+		let mut buf = [0; 8];
+		self.read_exact_bytes(&mut buf[8 - bytes..])?;
+		usize::try_from(u64::from_be_bytes(buf)).map_err(|_| Error::out_of_range(bytes))
+	}
+	/// Reads a little-endian [`usize`] encoded in `bytes` bytes, for formats which
+	/// encode lengths in less than [`read_usize_le`]'s fixed 8 bytes.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before exactly `bytes` bytes can
+	/// be read. Returns [`Error::OutOfRange`] if the value read doesn't fit in the
+	/// target's [`usize`].
+	///
+	/// # Panics
+	///
+	/// Panics if `bytes` is not in range `1..=8`.
+	///
+	/// [`read_usize_le`]: Self::read_usize_le
+	fn read_usize_sized_le(&mut self, bytes: usize) -> Result<usize> {
+		assert!(matches!(bytes, 1..=8), "usize width must be in range 1..=8, got {bytes}");
+		let mut buf = [0; 8];
+		self.read_exact_bytes(&mut buf[..bytes])?;
+		usize::try_from(u64::from_le_bytes(buf)).map_err(|_| Error::out_of_range(bytes))
+	}
+	/// Reads a big-endian Q7.8 fixed-point number, a signed [`i16`] with `8`
+	/// fractional bits.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let mut source: &[u8] = &[0x01, 0x80, 0xFF, 0x00];
+	/// assert_eq!(source.read_fixed_i16_q8()?, 1.5);
+	/// assert_eq!(source.read_fixed_i16_q8()?, -1.0);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before exactly `2` bytes can be
+	/// read.
+	fn read_fixed_i16_q8(&mut self) -> Result<f32> {
+		self.read_i16().map(|i| f32::from(i) / 256.0)
+	}
+	/// Reads an integer as ASCII decimal text, such as written by [`write_int_str`],
+	/// up to a `terminator` byte or the end of the stream. The terminator is
+	/// consumed, but not included in the text. Like [`str::parse`], the digits may
+	/// be preceded by `+`, or by `-` for signed types.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let mut source: &[u8] = b"-42,255\n";
+	/// assert_eq!(source.read_int_str::<i32>(b',')?, -42);
+	/// assert_eq!(source.read_int_str::<u8>(b'\n')?, 255);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before any byte is read. Returns
+	/// [`Error::ParseInt`] if the text is empty, or if the integer doesn't fit in
+	/// `T`, after consuming the text through the terminator. If a byte other than
+	/// a digit is read, [`Error::ParseInt`] is returned right away, with the rest
+	/// of the text left unconsumed.
+	///
+	/// # Implementation
+	///
+	/// The text is read one byte at a time, accumulating the value without an
+	/// intermediate buffer.
+	///
+	/// [`write_int_str`]: DataSink::write_int_str
+	fn read_int_str<T: PrimInt>(&mut self, terminator: u8) -> Result<T> where Self: Sized {
+		default_read_int_str(self, terminator)
+	}
 
 	/// Reads bytes into a slice, returning them as a UTF-8 string if valid.
 	///
@@ -319,6 +686,134 @@ pub trait DataSource {
 		let utf8 = from_utf8(bytes)?;
 		Ok(utf8)
 	}
+	/// Reads bytes into a slice, returning the valid UTF-8 string at its start and
+	/// the number of bytes consumed from the source. Unlike [`read_utf8`], an
+	/// incomplete codepoint at the end of the read bytes isn't an error: it's left
+	/// in `buf` after the string, and counted as consumed. When streaming text in
+	/// chunks, the caller can complete this codepoint from the source before
+	/// reading the next chunk.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let mut source = "hé!".as_bytes();
+	/// let mut buf = [0; 2];
+	/// let (str, count) = source.read_utf8_counted(&mut buf)?;
+	/// assert_eq!((str, count), ("h", 2));
+	/// // The first byte of 'é' is left after the string.
+	/// assert_eq!(buf[1], 0xC3);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Utf8`] if invalid UTF-8 is read, leaving the stream in an
+	/// undefined state like [`read_utf8`].
+	///
+	/// [`read_utf8`]: Self::read_utf8
+	#[cfg(feature = "utf8")]
+	fn read_utf8_counted<'a>(&mut self, buf: &'a mut [u8]) -> Result<(&'a str, usize)> {
+		let bytes = self.read_bytes(buf)?;
+		let count = bytes.len();
+		match from_utf8(bytes) {
+			Ok(utf8) => Ok((utf8, count)),
+			Err(error) if error.error_len().is_none() => {
+				let utf8 = unsafe {
+					// Safety: the bytes have been validated as UTF-8 up to this point.
+					core::str::from_utf8_unchecked(&bytes[..error.valid_up_to()])
+				};
+				Ok((utf8, count))
+			}
+			Err(error) => Err(error.into())
+		}
+	}
+	/// Reads up to `count` bytes with [`read_utf8`], appending them to `buf` and
+	/// returning the appended string. This is the bounded counterpart to
+	/// [`VecSource::read_utf8_to_end`], reusing the string's capacity rather than
+	/// reading into a separate byte buffer.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let mut source: &[u8] = b"Hello, World!";
+	/// let mut buf = String::from("> ");
+	/// assert_eq!(source.read_utf8_into(5, &mut buf)?, "Hello");
+	/// assert_eq!(buf, "> Hello");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Utf8`] if invalid UTF-8 is read. As with [`read_utf8`],
+	/// the valid bytes before the error, whose length is given by the error, are
+	/// appended to `buf`. [`Error::Allocation`] is returned when capacity cannot be
+	/// allocated, leaving `buf` unchanged. Returns any IO errors encountered.
+	///
+	/// [`read_utf8`]: Self::read_utf8
+	#[cfg(all(feature = "alloc", feature = "utf8"))]
+	fn read_utf8_into<'a>(&mut self, count: usize, buf: &'a mut alloc::string::String) -> Result<&'a str> {
+		buf.try_reserve(count)?;
+		let start = buf.len();
+		// Safety: the appended bytes are zeroed, which is valid UTF-8, then are
+		// truncated to the bytes known to be valid.
+		let bytes = unsafe { buf.as_mut_vec() };
+		bytes.resize(start + count, 0);
+		let target = bytes[start..].as_ptr();
+		let (read_count, trusted, result) = match self.read_utf8(&mut bytes[start..]) {
+			// A string at the start of the appended bytes is known to be valid.
+			Ok(str) => (str.len(), core::ptr::eq(str.as_ptr(), target), Ok(())),
+			Err(Error::Utf8(error)) => (error.valid_up_to(), false, Err(error.into())),
+			Err(error) => (0, true, Err(error))
+		};
+		// Otherwise, an implementation could report bytes it didn't validate, so
+		// check them before they become part of the string.
+		let read_count = if trusted {
+			read_count
+		} else {
+			bytes.get(start..start + read_count)
+				 .filter(|read| from_utf8(read).is_ok())
+				 .map_or(0, <[u8]>::len)
+		};
+		bytes.truncate(start + read_count);
+		result.map(|()| &buf[start..])
+	}
+	/// Reads a line ending in `\n`, appending it to `buf` with invalid UTF-8 replaced
+	/// by the replacement character `U+FFFD`, as [`String::from_utf8_lossy`] does.
+	/// Returns the number of bytes consumed, including the `\n`. Zero is returned
+	/// at the end of the stream, whereas an empty line returns one. The last line
+	/// may not end in `\n`.
+	///
+	/// Unlike [`read_utf8`], this never fails on invalid UTF-8, making it useful for
+	/// reading mostly-text data such as logs, which may contain corrupted bytes.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let mut source: &[u8] = b"ok\n\n\xFFbad\n";
+	/// let mut buf = String::new();
+	/// assert_eq!(source.read_line_lossy(&mut buf)?, 3);
+	/// assert_eq!(source.read_line_lossy(&mut buf)?, 1);
+	/// assert_eq!(source.read_line_lossy(&mut buf)?, 5);
+	/// assert_eq!(source.read_line_lossy(&mut buf)?, 0);
+	/// assert_eq!(buf, "ok\n\n\u{FFFD}bad\n");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered. The bytes read before the error are
+	/// appended to `buf`.
+	///
+	/// # Implementation
+	///
+	/// The default implementation reads one byte at a time. Sources with an
+	/// internal buffer search it for the line ending instead.
+	///
+	/// [`String::from_utf8_lossy`]: alloc::string::String::from_utf8_lossy
+	/// [`read_utf8`]: Self::read_utf8
+	#[cfg(all(feature = "alloc", feature = "utf8"))]
+	fn read_line_lossy(&mut self, buf: &mut alloc::string::String) -> Result<usize> {
+		let mut line = alloc::vec::Vec::new();
+		let result = default_read_until(self, b'\n', &mut line);
+		push_utf8_lossy(buf, &line, result)
+	}
 	/// Reads a single UTF-8 codepoint, returning a [`char`] if valid.
 	///
 	/// # Errors
@@ -333,6 +828,27 @@ pub trait DataSource {
 	fn read_utf8_codepoint(&mut self, buf: &mut [u8; 4]) -> Result<char> {
 		Ok(default_read_utf8_codepoint(self, buf)?.parse().unwrap())
 	}
+	/// Returns an iterator decoding UTF-8 codepoints from the source one at a time,
+	/// without reading the whole text into memory first. The iterator ends once the
+	/// stream ends cleanly between codepoints.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let source: &[u8] = "a🦀b".as_bytes();
+	/// let chars = source.chars().collect::<Result<String, _>>()?;
+	/// assert_eq!(chars, "a🦀b");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// The iterator yields [`Error::Utf8`] if an invalid codepoint is read, then
+	/// continues after it. [`Error::End`] is yielded if the stream ends partway
+	/// through a codepoint.
+	#[cfg(feature = "utf8")]
+	fn chars(self) -> Chars<Self> where Self: Sized {
+		Chars::new(self)
+	}
 	/// Reads bytes into a slice, returning them as an ASCII slice if valid.
 	///
 	/// # Errors
@@ -368,6 +884,66 @@ pub trait DataSource {
 	fn read_ascii<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [ascii::Char]> {
 		default_read_ascii(self, buf)
 	}
+
+	/// Reads bytes into a slice, returning them if valid ASCII. Like [`read_ascii`],
+	/// but returns the bytes rather than [`ascii::Char`]s, so is available without
+	/// the `unstable_ascii_char` feature.
+	///
+	/// ```
+	/// # use data_streams::{DataSource, Error};
+	/// let mut source = &b"GET /\xFF"[..];
+	/// assert_eq!(source.read_ascii_bytes(&mut [0; 3])?, b"GET");
+	/// assert_eq!(source.read_ascii_bytes(&mut [0; 4]), Err(Error::invalid_ascii(0xFF, 2, 2)));
+	/// // Slices only consume the valid bytes.
+	/// assert_eq!(source, b"\xFF");
+	/// # Ok::<_, Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Ascii`] if a non-ASCII byte is found. The read bytes are
+	/// left in `buf`, valid up to [`AsciiError::valid_up_to`]. Returns any IO errors
+	/// encountered.
+	///
+	/// [`read_ascii`]: Self::read_ascii
+	/// [`ascii::Char`]: core::ascii::Char
+	/// [`AsciiError::valid_up_to`]: crate::AsciiError::valid_up_to
+	fn read_ascii_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		default_read_ascii_bytes(self, buf)
+	}
+
+	/// Borrows the source by mutable reference. Adapters which take the source by
+	/// value can be given this reference instead, allowing reading to continue from
+	/// the source after the adapter is done.
+	///
+	/// ```
+	/// # use data_streams::DataSource;
+	/// let mut source = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12][..];
+	/// let mut header = std::io::Read::take(source.by_ref(), 10);
+	/// assert_eq!(header.read_exact_bytes(&mut [0; 10])?, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+	/// assert_eq!(source.read_u16()?, 0x0B0C);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	fn by_ref(&mut self) -> &mut Self where Self: Sized { self }
+}
+
+/// Reads an array with a size of `N` bytes, like [`DataSource::read_array`]. Unlike
+/// the method, which needs a `Self: Sized` bound to keep the trait object-safe,
+/// this can read from trait objects.
+///
+/// ```
+/// # use data_streams::{read_array, DataSource};
+/// let mut bytes = &[1u8, 2, 3, 4, 5][..];
+/// let source: &mut dyn DataSource = &mut bytes;
+/// assert_eq!(read_array::<4>(source)?, [1, 2, 3, 4]);
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::End`] with the array length if `N` bytes cannot be read.
+pub fn read_array<const N: usize>(source: &mut (impl DataSource + ?Sized)) -> Result<[u8; N]> {
+	default_read_array(source)
 }
 
 /// A helper macro which conditionally disables the default body of a method if
@@ -398,12 +974,116 @@ pub trait VecSource: DataSource {
 	///
 	/// # Errors
 	///
-	/// Returns any IO errors encountered.
+	/// Returns any IO errors encountered. With the `unstable_specialization`
+	/// feature, [`Error::NoEnd`] is returned without reading if the source is an
+	/// [`InfiniteSource`].
+	///
+	/// [`InfiniteSource`]: markers::InfiniteSource
 	fn read_to_end<'a>(&mut self, buf: &'a mut alloc::vec::Vec<u8>) -> Result<&'a [u8]> {
 		impls::read_to_end(self, buf, 0)
 	}
 	}
 
+	/// Reads up to `count` bytes into the spare capacity of `buf`, appending them
+	/// to the existing contents and returning the number of bytes read. Fewer than
+	/// `count` bytes are read only if the end of the stream is reached.
+	///
+	/// ```
+	/// # use std::collections::VecDeque;
+	/// # use data_streams::VecSource;
+	/// let mut source = VecDeque::from(*b"Hello, World!");
+	/// let mut buf = b"> ".to_vec();
+	/// assert_eq!(source.read_into_vec(&mut buf, 5)?, 5);
+	/// assert_eq!(buf, b"> Hello");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered. [`Error::Allocation`] is returned when
+	/// capacity cannot be allocated. Any bytes read before an error remain in the
+	/// spare capacity, but are not appended.
+	///
+	/// # Implementation
+	///
+	/// Bytes are read directly into the uninitialized capacity with
+	/// [`read_into_uninit`].
+	///
+	/// # Panics
+	///
+	/// Panics if [`read_into_uninit`] returns a slice other than the start of the
+	/// spare capacity.
+	///
+	/// [`read_into_uninit`]: DataSource::read_into_uninit
+	fn read_into_vec(&mut self, buf: &mut alloc::vec::Vec<u8>, count: usize) -> Result<usize> {
+		buf.try_reserve(count)?;
+		let spare = &mut buf.spare_capacity_mut()[..count];
+		let read = read_into_spare(self, spare)?;
+
+		unsafe {
+			// Safety: read_into_spare checked that the first read bytes of the spare
+			// capacity were initialized.
+			buf.set_len(buf.len() + read);
+		}
+		Ok(read)
+	}
+
+	/// Reads at most `max` bytes into `buf` until the end of the stream, returning
+	/// the bytes read, then checks the byte count against the source's [size bounds].
+	/// As [`SourceSize`] is unsafe to implement and relied on elsewhere, this is a
+	/// safety net for catching faulty implementations during development.
+	///
+	/// ```
+	/// # use std::collections::VecDeque;
+	/// # use data_streams::VecSource;
+	/// let mut source = VecDeque::from(*b"Hello, World!");
+	/// let mut buf = Vec::new();
+	/// assert_eq!(source.read_to_end_checked(&mut buf, 5)?, b"Hello");
+	/// assert_eq!(source.read_to_end_checked(&mut buf, 64)?, b", World!");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::SizeMismatch`] if fewer bytes than the lower bound were read
+	/// before the end of the stream, or more bytes than the upper bound were read.
+	/// Any bytes read remain in `buf`. Returns any IO errors encountered, and
+	/// [`Error::Allocation`] when capacity cannot be allocated.
+	///
+	/// [size bounds]: SourceSize
+	fn read_to_end_checked<'a>(&mut self, buf: &'a mut alloc::vec::Vec<u8>, max: usize) -> Result<&'a [u8]>
+	where
+		Self: SourceSize + Sized
+	{
+		const CHUNK_SIZE: usize = 8 * 1024;
+
+		let lower_bound = self.lower_bound();
+		let upper_bound = self.upper_bound();
+		let start = buf.len();
+		let mut count = 0;
+		let ended = loop {
+			let chunk = (max - count).min(CHUNK_SIZE);
+			if chunk == 0 {
+				break false
+			}
+
+			let read = self.read_into_vec(buf, chunk)?;
+			count += read;
+			if read < chunk {
+				break true
+			}
+		};
+
+		let read_count = count as u64;
+		let under = ended && read_count < lower_bound;
+		let over = upper_bound.is_some_and(|upper_bound| read_count > upper_bound);
+		if under || over {
+			Err(Error::size_mismatch(read_count, lower_bound, upper_bound))
+		} else {
+			Ok(&buf[start..])
+		}
+	}
+
 	spec_default! {
 	/// Reads UTF-8 bytes into `buf` until the end of the stream, returning the
 	/// string read. If invalid bytes are encountered, an error is returned and
@@ -414,7 +1094,11 @@ pub trait VecSource: DataSource {
 	///
 	/// Returns [`Error::Utf8`] if invalid UTF-8 is read. The stream is left in a
 	/// state with all bytes consumed from it. `buf` contains the read UTF-8 string
-	/// up to the invalid bytes.
+	/// up to the invalid bytes. With the `unstable_specialization` feature,
+	/// [`Error::NoEnd`] is returned without reading if the source is an
+	/// [`InfiniteSource`].
+	///
+	/// [`InfiniteSource`]: markers::InfiniteSource
 	#[cfg(feature = "utf8")]
 	fn read_utf8_to_end<'a>(&mut self, buf: &'a mut alloc::string::String) -> Result<&'a str> {
 		unsafe {
@@ -422,6 +1106,153 @@ pub trait VecSource: DataSource {
 		}
 	}
 	}
+
+	/// Reads UTF-8 bytes into `buf` until the end of the stream like [`read_utf8_to_end`],
+	/// first consuming a UTF-8 byte order mark if present. See [`BufferAccess::strip_bom`].
+	/// Unlike that method, a byte order mark split across buffer fills is also
+	/// consumed.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Utf8`] if invalid UTF-8 is read. The stream is left in a
+	/// state with all bytes consumed from it. `buf` contains the read UTF-8 string
+	/// up to the invalid bytes. [`Error::InsufficientBuffer`] is returned if the
+	/// buffer can't hold three bytes.
+	///
+	/// [`read_utf8_to_end`]: Self::read_utf8_to_end
+	#[cfg(feature = "utf8")]
+	fn read_utf8_to_end_skip_bom<'a>(&mut self, buf: &'a mut alloc::string::String) -> Result<&'a str>
+	where
+		Self: BufferAccess
+	{
+		const BOM: &[u8] = b"\xEF\xBB\xBF";
+		if self.strip_bom()? {
+			return self.read_utf8_to_end(buf)
+		}
+
+		// Buffers which can't be topped up, such as BufReader's, may hold only the
+		// start of the mark. Consume it a fill at a time while it matches.
+		let mut matched = 0;
+		while matched < BOM.len() {
+			let buffer = match self.request(1) {
+				Ok(_) => self.buffer(),
+				Err(Error::InsufficientBuffer { .. }) => self.fill_buffer()?,
+				Err(error) => return Err(error)
+			};
+			let len = buffer.len().min(BOM.len() - matched);
+			if len == 0 || buffer[..len] != BOM[matched..matched + len] {
+				break
+			}
+			self.drain_buffer(len);
+			matched += len;
+		}
+
+		if matched == 0 || matched == BOM.len() {
+			self.read_utf8_to_end(buf)
+		} else {
+			// Not a mark after all, so the consumed bytes start the string.
+			unsafe {
+				append_utf8(buf, |buf| {
+					buf.try_reserve(matched)?;
+					buf.extend_from_slice(&BOM[..matched]);
+					Ok(matched + self.read_to_end(buf)?.len())
+				})
+			}
+		}
+	}
+
+	/// Decodes codepoints, appending them to `buf` while `pred` returns `true`, and
+	/// returns the number of bytes appended. Reading stops at the end of the stream
+	/// or at the first rejected codepoint, which is peeked from the buffer and left
+	/// unconsumed. This is useful for reading identifiers or quoted strings.
+	///
+	/// ```
+	/// # use std::collections::VecDeque;
+	/// # use data_streams::VecSource;
+	/// let mut source = VecDeque::from("ident_1 = ...".as_bytes().to_vec());
+	/// let mut ident = String::new();
+	/// source.read_utf8_while(&mut ident, |c| c.is_alphanumeric() || c == '_')?;
+	/// assert_eq!(ident, "ident_1");
+	/// assert_eq!(source, b" = ...");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Utf8`] if invalid UTF-8 is read, with the invalid bytes left
+	/// unconsumed. Its [`valid_up_to`] is the number of bytes appended to `buf`.
+	/// [`Error::End`] is returned if the stream ends partway through a codepoint,
+	/// and [`Error::InsufficientBuffer`] if the buffer can't hold a whole codepoint.
+	/// [`Error::Allocation`] is returned when capacity cannot be allocated. Any
+	/// codepoints accepted before an error remain in `buf`.
+	///
+	/// [`valid_up_to`]: crate::Utf8Error::valid_up_to
+	#[cfg(feature = "utf8")]
+	fn read_utf8_while(&mut self, buf: &mut alloc::string::String, mut pred: impl FnMut(char) -> bool) -> Result<usize>
+	where
+		Self: BufferAccess + Sized
+	{
+		let start = buf.len();
+		loop {
+			if self.buffer().is_empty() {
+				self.fill_buffer()?;
+			}
+
+			let Some(&first_byte) = self.buffer().first() else { break };
+			// Invalid leading bytes have zero width; validate them on their own.
+			let char_width = utf8_char_width(first_byte).max(1);
+			self.require(char_width)?;
+			let spare_capacity = self.buffer_capacity().saturating_sub(self.buffer_count());
+			let bytes = self.buffer_contiguous()
+				.get(..char_width)
+				.ok_or(Error::insufficient_buffer(spare_capacity, char_width))?;
+			let char = match from_utf8(bytes) {
+				Ok(str) => str.chars().next().unwrap(),
+				Err(error) => {
+					let mut error = crate::Utf8Error::from(error);
+					error.set_offset(buf.len() - start);
+					return Err(error.into())
+				}
+			};
+
+			if !pred(char) {
+				break
+			}
+
+			buf.try_reserve(char_width)?;
+			buf.push(char);
+			self.drain_buffer(char_width);
+		}
+		Ok(buf.len() - start)
+	}
+}
+
+/// Extends a vector with bytes read from a [source](DataSource).
+#[cfg(feature = "alloc")]
+pub trait ExtendFromSource {
+	/// Reads bytes from `source` until its presumptive end, appending them to the
+	/// existing contents and returning the bytes read. Capacity is reserved up
+	/// front for sources whose [remaining](DataSource::remaining) count is known.
+	/// If an error is returned, any bytes read remain appended.
+	///
+	/// ```
+	/// # use data_streams::ExtendFromSource;
+	/// let mut buf = b"Hello, ".to_vec();
+	/// let mut source: &[u8] = b"World!";
+	/// assert_eq!(buf.extend_from_source(&mut source)?, b"World!");
+	/// assert_eq!(buf, b"Hello, World!");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered. [`Error::Allocation`] is returned when
+	/// capacity cannot be allocated. With the `unstable_specialization` feature,
+	/// [`Error::NoEnd`] is returned without reading if the source is an
+	/// [`InfiniteSource`].
+	///
+	/// [`InfiniteSource`]: markers::InfiniteSource
+	fn extend_from_source(&mut self, source: &mut (impl DataSource + ?Sized)) -> Result<&[u8]>;
 }
 
 /// Reads generic data from a [source](DataSource).
@@ -446,39 +1277,273 @@ pub trait GenericDataSource<T: Pod>: DataSource {
 		self.read_data().map(T::from_le)
 	}
 
+	/// Reads a big-endian fixed-point number with `frac_bits` fractional bits, such
+	/// as a 16.16 number with an [`i32`] and `16` fractional bits.
+	///
+	/// ```
+	/// # use data_streams::GenericDataSource;
+	/// let mut source: &[u8] = &[0x00, 0x01, 0x40, 0x00];
+	/// assert_eq!(GenericDataSource::<i32>::read_fixed(&mut source, 16)?, 1.25);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before exactly the type's size in
+	/// bytes can be read.
+	///
+	/// # Panics
+	///
+	/// Panics if `frac_bits` exceeds the bit width of `T`.
+	fn read_fixed(&mut self, frac_bits: u32) -> Result<f64> where T: PrimInt {
+		self.read_int().map(|i| fixed_to_float(i, frac_bits))
+	}
+
+	/// Reads a little-endian fixed-point number with `frac_bits` fractional bits.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before exactly the type's size in
+	/// bytes can be read.
+	///
+	/// # Panics
+	///
+	/// Panics if `frac_bits` exceeds the bit width of `T`.
+	fn read_fixed_le(&mut self, frac_bits: u32) -> Result<f64> where T: PrimInt {
+		self.read_int_le().map(|i| fixed_to_float(i, frac_bits))
+	}
+
 	/// Reads a value of generic type `T` supporting an arbitrary bit pattern. See
 	/// [`Pod`].
 	///
+	/// The bytes are read into a value on the stack, which is aligned for `T`, so
+	/// the alignment of the source's bytes doesn't matter. Zero-sized types are read
+	/// without touching the stream.
+	///
 	/// # Errors
 	///
 	/// Returns [`Error::End`] if the stream ends before exactly the type's size in
 	/// bytes can be read.
 	fn read_data(&mut self) -> Result<T> {
-		let mut value = T::zeroed();
-		self.read_exact_bytes(bytes_of_mut(&mut value))?;
-		Ok(value)
+		default_read_data(self)
 	}
 	
 	/// Reads multiple values of generic type `T` supporting an arbitrary bit pattern,
-	/// returning the read values.
+	/// returning the read values. Only whole values are read; see
+	/// [`read_aligned_bytes`] for whether the bytes of a partial value at the end of
+	/// the stream are consumed.
+	///
+	/// Here, the trailing byte of a five-byte slice is left unconsumed:
+	///
+	/// ```
+	/// # use data_streams::{DataSource, GenericDataSource};
+	/// let mut source: &[u8] = &[0, 1, 0, 2, 3];
+	/// let mut buf = [0; 4];
+	/// let values = GenericDataSource::<u16>::read_data_slice(&mut source, &mut buf)?;
+	/// assert_eq!(values.len(), 2);
+	/// assert_eq!(source.read_bytes(&mut [0; 4])?, [3]);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// A slice of `T` is always aligned for `T`, so viewing `buf` as bytes to read
+	/// into can't fail. To read into a byte buffer instead, such as one from a pool
+	/// shared between types, use [`try_read_data_slice`].
 	/// 
 	/// # Errors
 	/// 
-	/// Returns any IO errors encountered.
-	/// 
-	/// # Panics
-	/// 
-	/// Panics if the [`DataSource::read_aligned_bytes`] implementation returns an unaligned slice.
+	/// Returns any IO errors encountered. [`Error::Misaligned`] is returned if the
+	/// [`DataSource::read_aligned_bytes`] implementation returns an unaligned slice.
+	/// In debug builds, this panics instead to catch faulty implementations early.
+	///
+	/// [`read_aligned_bytes`]: DataSource::read_aligned_bytes
+	/// [`try_read_data_slice`]: Self::try_read_data_slice
 	fn read_data_slice<'a>(&mut self, buf: &'a mut [T]) -> Result<&'a [T]> {
-		let bytes = self.read_aligned_bytes(cast_slice_mut(buf), size_of::<T>())?;
-		assert_eq!(bytes.len() % size_of::<T>(), 0, "unaligned read implementation");
-		Ok(cast_slice(buf))
+		let alignment = size_of::<T>();
+		if alignment == 0 {
+			// Zero-sized values take no bytes to read.
+			return Ok(buf)
+		}
+
+		let count = self.read_aligned_bytes(cast_slice_mut(buf), alignment)?.len();
+		debug_assert_eq!(count % alignment, 0, "unaligned read implementation");
+		if count % alignment != 0 {
+			return Err(Error::misaligned(count, alignment))
+		}
+		Ok(cast_slice(&buf[..count / alignment]))
+	}
+
+	/// Reads multiple values of generic type `T` supporting an arbitrary bit pattern
+	/// into a byte buffer, returning the read values. Like [`read_data_slice`], only
+	/// whole values are read. Bytes past the last whole value that fits in `buf`
+	/// are left untouched.
+	///
+	/// ```
+	/// # use data_streams::{Error, GenericDataSource};
+	/// #[repr(align(4))]
+	/// struct Aligned([u8; 12]);
+	///
+	/// let mut buf = Aligned([0; 12]);
+	/// let mut source: &[u8] = &[0; 10];
+	/// assert_eq!(
+	///     GenericDataSource::<u32>::try_read_data_slice(&mut source, &mut buf.0[1..]),
+	///     Err(Error::unaligned_buffer(4))
+	/// );
+	/// assert_eq!(GenericDataSource::<u32>::try_read_data_slice(&mut source, &mut buf.0)?, [0, 0]);
+	/// # Ok::<_, Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::UnalignedBuffer`] if `buf` isn't aligned for `T`, without
+	/// reading. Otherwise, errors are returned as in [`read_data_slice`].
+	///
+	/// [`read_data_slice`]: Self::read_data_slice
+	fn try_read_data_slice<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [T]> {
+		let alignment = align_of::<T>();
+		if buf.as_ptr().align_offset(alignment) != 0 {
+			return Err(Error::unaligned_buffer(alignment))
+		}
+
+		let size = size_of::<T>();
+		if size == 0 {
+			// The count of zero-sized values in a byte buffer is undefined.
+			return Ok(&[])
+		}
+
+		let count = self.read_aligned_bytes(buf, size)?.len();
+		debug_assert_eq!(count.checked_rem(size).unwrap_or_default(), 0, "unaligned read implementation");
+		try_cast_slice(&buf[..count]).map_err(|_| Error::misaligned(count, size))
+	}
+
+	/// Reads exactly `N` values of generic type `T` supporting an arbitrary bit
+	/// pattern into an array. See [`Pod`]. Unlike [`read_data_slice`], this never
+	/// returns a partial array.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before exactly `N` values can be
+	/// read. The stream is left just after any bytes consumed before the end, as
+	/// with [`read_exact_bytes`].
+	///
+	/// [`read_data_slice`]: Self::read_data_slice
+	/// [`read_exact_bytes`]: DataSource::read_exact_bytes
+	fn read_data_array<const N: usize>(&mut self) -> Result<[T; N]>
+	where
+		Self: Sized
+	{
+		let mut array = [T::zeroed(); N];
+		if size_of::<T>() > 0 {
+			self.read_exact_bytes(cast_slice_mut(&mut array[..]))?;
+		}
+		Ok(array)
+	}
+
+	/// Reads exactly `N` values of generic type `T` into an array, returning `None`
+	/// if the stream ended cleanly before the first byte. This distinguishes the end
+	/// of a sequence of fixed-size records from a truncated record.
+	///
+	/// ```
+	/// # use data_streams::{Error, GenericDataSource};
+	/// let mut source: &[u8] = &[1, 2, 3, 4, 5];
+	/// assert_eq!(source.try_read_data_array::<2>()?, Some([1u8, 2]));
+	/// assert_eq!(source.try_read_data_array::<2>()?, Some([3u8, 4]));
+	/// assert_eq!(GenericDataSource::<u8>::try_read_data_array::<2>(&mut source), Err(Error::end(2, 0)));
+	/// source = &[];
+	/// assert_eq!(GenericDataSource::<u8>::try_read_data_array::<2>(&mut source)?, None);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends after the first byte, but before
+	/// exactly `N` values can be read.
+	fn try_read_data_array<const N: usize>(&mut self) -> Result<Option<[T; N]>>
+	where
+		Self: Sized
+	{
+		if N * size_of::<T>() > 0 && !self.request(1)? {
+			return Ok(None)
+		}
+		self.read_data_array().map(Some)
+	}
+
+	/// Reads exactly `count` values of generic type `T` supporting an arbitrary bit
+	/// pattern into a new vector. See [`Pod`].
+	///
+	/// If the source's [remaining](DataSource::remaining) byte count is known and
+	/// too small to read `count` values, an error is returned before allocating,
+	/// preventing huge allocations from corrupt length fields.
+	///
+	/// ```
+	/// # use data_streams::GenericDataSource;
+	/// let mut source: &[u8] = &[0, 1, 0, 2, 0, 3];
+	/// let samples: Vec<u16> = source.read_data_vec(3)?;
+	/// assert_eq!(samples, [1, 2, 3].map(u16::to_be));
+	///
+	/// // Fails without allocating
+	/// let mut source: &[u8] = &[0; 16];
+	/// assert!(GenericDataSource::<u64>::read_data_vec(&mut source, usize::MAX).is_err());
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before exactly `count` values can
+	/// be read. [`Error::Allocation`] is returned when capacity cannot be allocated.
+	#[cfg(feature = "alloc")]
+	fn read_data_vec(&mut self, count: usize) -> Result<alloc::vec::Vec<T>> {
+		let byte_count = count.saturating_mul(size_of::<T>());
+		if self.remaining().is_some_and(|remaining| remaining < byte_count as u64) {
+			return Err(Error::end(byte_count, 0))
+		}
+
+		let mut vec = alloc::vec::Vec::new();
+		vec.try_reserve_exact(count)?;
+		vec.resize(count, T::zeroed());
+		if byte_count > 0 {
+			self.read_exact_bytes(cast_slice_mut(&mut vec))?;
+		}
+		Ok(vec)
 	}
 }
 
+#[cfg(not(feature = "unstable_specialization"))]
 impl<S: DataSource + ?Sized, T: Pod> GenericDataSource<T> for S { }
 
+#[cfg(feature = "unstable_specialization")]
+impl<S: DataSource + ?Sized, T: Pod> GenericDataSource<T> for S {
+	default fn read_data(&mut self) -> Result<T> {
+		default_read_data(self)
+	}
+}
+
+#[cfg(feature = "unstable_specialization")]
+impl<S: BufferAccess + ?Sized, T: Pod> GenericDataSource<T> for S {
+	fn read_data(&mut self) -> Result<T> {
+		buf_read_data(self)
+	}
+}
+
 /// Accesses a source's internal buffer.
+///
+/// Buffered sources can peek at upcoming bytes without consuming them, with
+/// methods such as [`try_peek_array`] and [`peek_data`]. In-memory sources like
+/// `&[u8]`, [`Vec`], and [`VecDeque`] implement this, as does [`BufReader`]. Other
+/// sources, such as a raw reader, can be wrapped in a [`PushbackSource`] for small
+/// lookahead.
+///
+/// A source holding all its data in the buffer, whose capacity may be just its
+/// length, should also report its [`remaining`] byte count. The default [`request`]
+/// then returns `false` for counts past the end, rather than failing to grow the
+/// buffer with [`Error::InsufficientBuffer`].
+///
+/// [`remaining`]: DataSource::remaining
+/// [`request`]: DataSource::request
+/// [`try_peek_array`]: Self::try_peek_array
+/// [`peek_data`]: Self::peek_data
+/// [`Vec`]: alloc::vec::Vec
+/// [`VecDeque`]: alloc::collections::VecDeque
+/// [`BufReader`]: std::io::BufReader
 pub trait BufferAccess: DataSource {
 	/// Returns the capacity of the internal buffer.
 	fn buffer_capacity(&self) -> usize;
@@ -495,6 +1560,78 @@ pub trait BufferAccess: DataSource {
 	/// 
 	/// Returns any IO errors encountered.
 	fn fill_buffer(&mut self) -> Result<&[u8]>;
+	/// Fills the internal buffer until at least `count` bytes are buffered, returning
+	/// the whole buffered window. Unlike [`fill_buffer`], which may return any number
+	/// of bytes, this guarantees the window is large enough to parse from directly.
+	///
+	/// ```
+	/// # use std::collections::VecDeque;
+	/// # use data_streams::{BufferAccess, Error};
+	/// let mut source = VecDeque::from(b"Hello".to_vec());
+	/// assert_eq!(source.fill_exact(4)?, b"Hello");
+	/// assert_eq!(source.fill_exact(6), Err(Error::end(6, 5)));
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] with the buffered byte count if a fill adds no bytes
+	/// before `count` bytes are buffered. No bytes are consumed in this case. Note
+	/// that sources which only refill once empty, such as [`BufReader`], can't add
+	/// to a partially filled buffer. [`Error::InsufficientBuffer`] is returned if
+	/// `count` exceeds the buffer capacity and the buffer [can't grow].
+	///
+	/// [`fill_buffer`]: Self::fill_buffer
+	/// [`BufReader`]: std::io::BufReader
+	/// [can't grow]: Self::grow_buffer
+	fn fill_exact(&mut self, count: usize) -> Result<&[u8]> {
+		if (*self).remaining().is_some_and(|remaining| remaining < count as u64) {
+			return Err(Error::end(count, self.buffer_count()))
+		}
+
+		if count > self.buffer_capacity() {
+			self.grow_buffer(count)?;
+		}
+
+		while self.buffer_count() < count {
+			let buf_len = self.buffer_count();
+			self.fill_buffer()?;
+			if self.buffer_count() <= buf_len {
+				return Err(Error::end(count, buf_len))
+			}
+		}
+		Ok(self.buffer_contiguous())
+	}
+	/// Grows the internal buffer to hold at least `min_capacity` bytes, if it can.
+	/// The default [`request`] calls this when the buffer is too small for the
+	/// requested count, before filling it.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::InsufficientBuffer`] if the buffer can't grow. This is the
+	/// default, for fixed-size buffers. [`Error::Allocation`] may be returned when
+	/// capacity cannot be allocated.
+	///
+	/// [`request`]: DataSource::request
+	fn grow_buffer(&mut self, min_capacity: usize) -> Result {
+		let buf_len = self.buffer_count();
+		Err(Error::insufficient_buffer(
+			self.buffer_capacity().saturating_sub(buf_len),
+			min_capacity.saturating_sub(buf_len)
+		))
+	}
+	/// Returns a slice over the whole filled portion of the internal buffer, first
+	/// rearranging it into one slice if needed. Unlike [`buffer`](Self::buffer),
+	/// this never returns fewer bytes than are buffered.
+	///
+	/// Buffers split in memory, such as a wrapped-around [`VecDeque`], are made
+	/// contiguous by moving their contents, which costs O(n) in the buffered byte
+	/// count. By default, sources return their existing buffer unchanged.
+	///
+	/// [`VecDeque`]: alloc::collections::VecDeque
+	fn buffer_contiguous(&mut self) -> &[u8] {
+		(*self).buffer()
+	}
 	/// Clears the internal buffer.
 	fn clear_buffer(&mut self) {
 		self.drain_buffer(self.buffer_count());
@@ -507,6 +1644,201 @@ pub trait BufferAccess: DataSource {
 	/// 
 	/// This method panics if `count` exceeds the buffer length.
 	fn drain_buffer(&mut self, count: usize);
+	/// Requests `N` bytes, returning a reference to them as an array in the internal
+	/// buffer. The bytes are not consumed; call [`drain_buffer`] with `N` once done
+	/// with them.
+	///
+	/// ```
+	/// # use data_streams::BufferAccess;
+	/// let mut source = &b"RIFF\0\0\0\0"[..];
+	/// assert_eq!(source.read_array_ref::<4>()?, b"RIFF");
+	/// source.drain_buffer(4);
+	/// assert_eq!(source, b"\0\0\0\0");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before `N` bytes could be read.
+	/// [`Error::InsufficientBuffer`] is returned if the buffer can't hold `N` bytes
+	/// contiguously.
+	///
+	/// [`drain_buffer`]: Self::drain_buffer
+	fn read_array_ref<const N: usize>(&mut self) -> Result<&[u8; N]> where Self: Sized {
+		self.require(N)?;
+		let spare_capacity = self.buffer_capacity().saturating_sub(self.buffer_count());
+		self.buffer_contiguous()
+			.first_chunk()
+			.ok_or(Error::insufficient_buffer(spare_capacity, N))
+	}
+	/// Reads a value of an arbitrary bit pattern from the buffer without consuming
+	/// it, for example to inspect a header before deciding whether to read it. See
+	/// [`Pod`].
+	///
+	/// ```
+	/// # use data_streams::BufferAccess;
+	/// let mut source = &[1u8, 2, 3][..];
+	/// assert_eq!(source.peek_data::<[u8; 2]>()?, [1, 2]);
+	/// assert_eq!(source, [1, 2, 3]);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before the value could be read.
+	/// [`Error::InsufficientBuffer`] is returned if the buffer can't hold the value.
+	/// The stream is never advanced.
+	fn peek_data<T: Pod>(&mut self) -> Result<T> where Self: Sized {
+		let size = size_of::<T>();
+		self.require(size)?;
+		let spare_capacity = self.buffer_capacity().saturating_sub(self.buffer_count());
+		self.buffer_contiguous()
+			.get(..size)
+			.map(bytemuck::pod_read_unaligned)
+			.ok_or(Error::insufficient_buffer(spare_capacity, size))
+	}
+	/// Reads a big-endian integer from the buffer without consuming it, like
+	/// [`peek_data`].
+	///
+	/// ```
+	/// # use data_streams::BufferAccess;
+	/// let mut source = &[0xCA, 0xFE, 0xBA, 0xBE][..];
+	/// assert_eq!(source.peek_int::<u16>()?, 0xCAFE);
+	/// assert_eq!(source.len(), 4);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if the stream ends before the integer could be read.
+	/// [`Error::InsufficientBuffer`] is returned if the buffer can't hold the
+	/// integer. The stream is never advanced.
+	///
+	/// [`peek_data`]: Self::peek_data
+	fn peek_int<T: PrimInt + Pod>(&mut self) -> Result<T> where Self: Sized {
+		self.peek_data().map(T::from_be)
+	}
+	/// Reads `N` bytes into an array without consuming them, or returns `None` if
+	/// the stream ends first.
+	///
+	/// ```
+	/// # use data_streams::BufferAccess;
+	/// let mut source = &b"PK\x03\x04"[..];
+	/// assert_eq!(source.try_peek_array()?, Some(*b"PK"));
+	/// assert_eq!(source.try_peek_array::<5>()?, None);
+	/// assert_eq!(source.len(), 4);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::InsufficientBuffer`] if the buffer can't hold `N` bytes.
+	/// The stream is never advanced.
+	fn try_peek_array<const N: usize>(&mut self) -> Result<Option<[u8; N]>> where Self: Sized {
+		if !self.request(N)? {
+			return Ok(None)
+		}
+
+		let spare_capacity = self.buffer_capacity().saturating_sub(self.buffer_count());
+		self.buffer_contiguous()
+			.first_chunk()
+			.copied()
+			.map(Some)
+			.ok_or(Error::insufficient_buffer(spare_capacity, N))
+	}
+	/// Checks whether the next `N` bytes match `expected` without consuming them,
+	/// returning `false` if they differ or the stream ends first. Parsers can use
+	/// this to detect a format by its magic number before reading it.
+	///
+	/// ```
+	/// # use data_streams::BufferAccess;
+	/// let mut source = &b"GIF89a"[..];
+	/// assert!(!source.peek_magic(b"\x89PNG")?);
+	/// assert!(source.peek_magic(b"GIF8")?);
+	/// assert_eq!(source.len(), 6);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::InsufficientBuffer`] if the buffer can't hold `N` bytes.
+	/// The stream is never advanced.
+	fn peek_magic<const N: usize>(&mut self, expected: &[u8; N]) -> Result<bool> where Self: Sized {
+		Ok(self.try_peek_array::<N>()?.is_some_and(|found| &found == expected))
+	}
+	/// Consumes a UTF-8 byte order mark (`EF BB BF`) at the start of the buffer, if
+	/// present, returning whether it was found. Call this before reading text which
+	/// may start with a BOM, such as files written by some Windows programs.
+	///
+	/// ```
+	/// # use data_streams::BufferAccess;
+	/// let mut source = &b"\xEF\xBB\xBFtext"[..];
+	/// assert!(source.strip_bom()?);
+	/// assert_eq!(source, b"text");
+	/// assert!(!source.strip_bom()?);
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered. [`Error::InsufficientBuffer`] is returned
+	/// if the buffer can't hold three bytes.
+	fn strip_bom(&mut self) -> Result<bool> {
+		const BOM: &[u8] = b"\xEF\xBB\xBF";
+		self.request(BOM.len())?;
+		let has_bom = self.buffer().starts_with(BOM);
+		if has_bom {
+			self.drain_buffer(BOM.len());
+		}
+		Ok(has_bom)
+	}
+	/// Writes up to `count` bytes from the internal buffer into `sink`, draining
+	/// them and refilling the buffer as needed. Returns the number of bytes
+	/// written, which is less than `count` only if the end of the stream is reached.
+	/// Bytes are written in buffer-sized chunks, without copying through an
+	/// intermediate buffer.
+	///
+	/// ```
+	/// # use data_streams::BufferAccess;
+	/// let mut source = &b"Hello, World!"[..];
+	/// let mut sink = Vec::new();
+	/// assert_eq!(source.drain_to_sink(&mut sink, 5)?, 5);
+	/// assert_eq!(sink, b"Hello");
+	/// assert_eq!(source, b", World!");
+	/// # Ok::<_, data_streams::Error>(())
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors encountered. If the sink returns [`Error::Overflow`],
+	/// the bytes it accepted are drained before returning the error, whose written
+	/// count is the total transferred.
+	fn drain_to_sink(&mut self, sink: &mut (impl DataSink + ?Sized), count: usize) -> Result<usize> where Self: Sized {
+		let mut transferred = 0;
+		while transferred < count {
+			if self.buffer().is_empty() {
+				self.fill_buffer()?;
+			}
+
+			let buf = self.buffer();
+			if buf.is_empty() {
+				break
+			}
+
+			let len = buf.len().min(count - transferred);
+			match sink.write_bytes(&buf[..len]) {
+				Ok(()) => { }
+				Err(Error::Overflow { remaining, written }) => {
+					self.drain_buffer(written);
+					return Err(Error::overflow(remaining, transferred + written))
+				}
+				Err(error) => return Err(error)
+			}
+			self.drain_buffer(len);
+			transferred += len;
+		}
+		Ok(transferred)
+	}
 	/// Bypasses the internal buffer by returning the underlying source, or `self`
 	/// if this behavior is not supported. Note that not fully draining the buffer
 	/// before bypassing it will cause data loss.
@@ -522,12 +1854,22 @@ impl<T: BufferAccess + ?Sized> DataSource for T {
 		self.buffer_count()
 	}
 
+	default fn remaining(&self) -> Option<u64> { None }
+
 	default fn request(&mut self, count: usize) -> Result<bool> {
 		default_request(self, count)
 	}
 
+	default fn read_u8(&mut self) -> Result<u8> {
+		buf_read_u8(self)
+	}
+
+	default fn read_i8(&mut self) -> Result<i8> {
+		self.read_u8().map(u8::cast_signed)
+	}
+
 	default fn skip(&mut self, count: usize) -> Result<usize> {
-		Ok(default_skip(self, count))
+		default_skip(self, count)
 	}
 
 	default fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
@@ -551,6 +1893,11 @@ impl<T: BufferAccess + ?Sized> DataSource for T {
 	/// If the alignment is zero or `buf`'s length is less than the alignment, the returned slice is
 	/// empty.
 	///
+	/// The largest aligned multiple in the buffer is copied at once. A value split
+	/// across the end of the buffer is read on its own, after which copying from
+	/// the buffer continues. If the stream ends partway through a value, its bytes
+	/// are consumed but not returned.
+	///
 	/// # Errors
 	///
 	/// Returns any IO errors encountered.
@@ -560,7 +1907,7 @@ impl<T: BufferAccess + ?Sized> DataSource for T {
 	/// 
 	/// [capacity]: Self::buffer_capacity
 	default fn read_aligned_bytes<'a>(&mut self, buf: &'a mut [u8], alignment: usize) -> Result<&'a [u8]> {
-		if alignment == 0 { return Ok(&[]) }
+		if alignment == 0 || buf.is_empty() { return Ok(&[]) }
 		if self.buffer_capacity() < alignment {
 			let spare_capacity = self.buffer_capacity() - self.buffer_count();
 			return Err(Error::InsufficientBuffer {
@@ -569,14 +1916,53 @@ impl<T: BufferAccess + ?Sized> DataSource for T {
 			})
 		}
 		
-		let len = buf.len() / alignment * alignment;
-		buf_read_bytes(
-			self,
-			&mut buf[..len],
-			|buf| buf.len() < alignment,
-			|mut source, buf|
-				source.read_aligned_bytes(buf, alignment).map(<[u8]>::len)
-		)
+		let len = max_multiple_of(buf.len(), alignment);
+		let mut count = 0;
+		while count < len {
+			if self.buffer().is_empty() {
+				self.fill_buffer()?;
+			}
+
+			let buffer = self.buffer();
+			if buffer.is_empty() {
+				break
+			}
+
+			let chunk_len = max_multiple_of(buffer.len().min(len - count), alignment);
+			if chunk_len > 0 {
+				buf[count..count + chunk_len].copy_from_slice(&buffer[..chunk_len]);
+				self.drain_buffer(chunk_len);
+				count += chunk_len;
+			} else {
+				// The next value is split across the end of the buffer. If the source
+				// knows it ends before the value does, leave the partial value
+				// unconsumed rather than reading it into the discarded remainder.
+				if (*self).remaining().is_some_and(|remaining| remaining < alignment as u64) {
+					break
+				}
+				match self.read_exact_bytes(&mut buf[count..count + alignment]) {
+					Ok(_) => count += alignment,
+					Err(Error::End { .. }) => break,
+					Err(error) => return Err(error)
+				}
+			}
+		}
+
+		Ok(&buf[..count])
+	}
+
+	#[cfg(feature = "unstable_borrowed_buf")]
+	default fn read_into_borrowed(&mut self, cursor: &mut BorrowedCursor<'_>) -> Result<usize> {
+		buf_read_into_borrowed(self, cursor)
+	}
+
+	default fn read_into_uninit<'a>(&mut self, buf: &'a mut [MaybeUninit<u8>]) -> Result<&'a [u8]> {
+		default_read_into_uninit(self, buf)
+	}
+
+	#[cfg(all(feature = "alloc", feature = "utf8"))]
+	default fn read_line_lossy(&mut self, buf: &mut alloc::string::String) -> Result<usize> {
+		buf_read_line_lossy(self, buf)
 	}
 
 	#[cfg(feature = "utf8")]
@@ -615,6 +2001,10 @@ impl<T: BufferAccess + ?Sized> DataSource for T {
 		Ok(str.parse().unwrap())
 	}
 
+	default fn read_ascii_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		default_read_ascii_bytes(self, buf)
+	}
+
 	#[cfg(feature = "unstable_ascii_char")]
 	default fn read_ascii<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [ascii::Char]> {
 		default_read_ascii(self, buf)
@@ -641,32 +2031,174 @@ pub(crate) const fn max_multiple_of(value: usize, factor: usize) -> usize {
 
 #[allow(dead_code)]
 pub(crate) fn default_request(source: &mut (impl BufferAccess + ?Sized), count: usize) -> Result<bool> {
+	// A source which knows it has too few bytes left, such as an in-memory buffer
+	// holding all its data, can't satisfy the request by growing its buffer.
+	if (*source).remaining().is_some_and(|remaining| remaining < count as u64) {
+		return Ok(false)
+	}
+
 	if source.available() < count {
-		let buf_len = source.buffer_count();
-		let spare_capacity = source.buffer_capacity() - buf_len;
-		if source.buffer_capacity() > 0 && count < spare_capacity {
-			Ok(source.fill_buffer()?.len() >= count)
-		} else {
-			Err(Error::InsufficientBuffer {
-				spare_capacity,
-				required_count: count - buf_len,
-			})
+		let spare_capacity = source.buffer_capacity() - source.buffer_count();
+		if source.buffer_capacity() == 0 || count >= spare_capacity {
+			source.grow_buffer(count)?;
 		}
+		Ok(source.fill_buffer()?.len() >= count)
 	} else {
 		Ok(true)
 	}
 }
 
-// Todo: after consuming, loop fill_buf and consume.
+/// Skips `count` bytes by draining the buffer, refilling it once empty until
+/// `count` bytes are skipped or the source ends. Returns the number of bytes
+/// skipped. An error while refilling is returned rather than the short count.
+#[allow(dead_code)]
+pub(crate) fn default_skip(source: &mut (impl BufferAccess + ?Sized), count: usize) -> Result<usize> {
+	let mut skip_count = 0;
+	while skip_count < count {
+		if (*source).available() == 0 {
+			source.fill_buffer()?;
+		}
+
+		let avail = (*source).available();
+		if avail == 0 {
+			break
+		}
+
+		let cur_skip_count = avail.min(count - skip_count);
+		source.drain_buffer(cur_skip_count);
+		// Guard against faulty implementations by verifying that the buffered
+		// bytes were removed.
+		assert_eq!((*source).available(), avail - cur_skip_count);
+		skip_count += cur_skip_count;
+	}
+	Ok(skip_count)
+}
+
+/// Reads a byte directly from the buffer, only filling it if empty. This skips the
+/// [`read_data`] path, speeding up byte-at-a-time parsing.
+///
+/// [`read_data`]: GenericDataSource::read_data
+#[allow(dead_code)]
+#[inline]
+pub(crate) fn buf_read_u8(source: &mut (impl BufferAccess + ?Sized)) -> Result<u8> {
+	#[cold]
+	fn fill(source: &mut (impl BufferAccess + ?Sized)) -> Result<u8> {
+		let &byte = source.fill_buffer()?.first().ok_or(Error::end(1, 0))?;
+		source.drain_buffer(1);
+		Ok(byte)
+	}
+
+	let Some(&byte) = source.buffer().first() else { return fill(source) };
+	source.drain_buffer(1);
+	Ok(byte)
+}
+
+/// Reads bytes into `line` one at a time, until `delimiter` is read or the source
+/// ends.
+#[cfg(all(feature = "alloc", feature = "utf8"))]
+fn default_read_until(source: &mut (impl DataSource + ?Sized), delimiter: u8, line: &mut alloc::vec::Vec<u8>) -> Result {
+	loop {
+		match source.read_u8() {
+			Ok(byte) => {
+				line.push(byte);
+				if byte == delimiter {
+					return Ok(())
+				}
+			}
+			Err(Error::End { .. }) => return Ok(()),
+			Err(error) => return Err(error)
+		}
+	}
+}
+
+/// Reads bytes into `line` until `delimiter` is read or the source ends, searching
+/// the buffer for the delimiter.
+#[cfg(all(feature = "alloc", feature = "utf8"))]
+fn buf_read_until(source: &mut (impl BufferAccess + ?Sized), delimiter: u8, line: &mut alloc::vec::Vec<u8>) -> Result {
+	loop {
+		if source.buffer().is_empty() {
+			source.fill_buffer()?;
+		}
+
+		let buffer = source.buffer();
+		if buffer.is_empty() {
+			return Ok(())
+		}
+
+		if let Some(index) = buffer.iter().position(|&byte| byte == delimiter) {
+			line.extend_from_slice(&buffer[..=index]);
+			source.drain_buffer(index + 1);
+			return Ok(())
+		}
+
+		let len = buffer.len();
+		line.extend_from_slice(buffer);
+		source.drain_buffer(len);
+	}
+}
+
+#[cfg(all(feature = "alloc", feature = "utf8"))]
 #[allow(dead_code)]
-pub(crate) fn default_skip(source: &mut (impl BufferAccess + ?Sized), mut count: usize) -> usize {
-	let avail = source.available();
-	count = count.min(avail);
-	source.drain_buffer(count);
-	// Guard against faulty implementations by verifying that the buffered
-	// bytes were removed.
-	assert_eq!(source.available(), avail.saturating_sub(count));
-	avail
+pub(crate) fn buf_read_line_lossy(source: &mut (impl BufferAccess + ?Sized), buf: &mut alloc::string::String) -> Result<usize> {
+	let mut line = alloc::vec::Vec::new();
+	let result = buf_read_until(source, b'\n', &mut line);
+	push_utf8_lossy(buf, &line, result)
+}
+
+/// Appends `line` to `buf` lossily, returning its length if `result` is `Ok`.
+#[cfg(all(feature = "alloc", feature = "utf8"))]
+fn push_utf8_lossy(buf: &mut alloc::string::String, line: &[u8], result: Result) -> Result<usize> {
+	buf.push_str(&alloc::string::String::from_utf8_lossy(line));
+	result.map(|()| line.len())
+}
+
+fn default_read_int_str<T: PrimInt>(source: &mut (impl DataSource + ?Sized), terminator: u8) -> Result<T> {
+	use core::num::IntErrorKind::{Empty, InvalidDigit, NegOverflow, PosOverflow};
+
+	let signed = T::min_value() < T::zero();
+	let ten = T::from(10).expect("10 should fit in an integer type");
+	let mut value = Some(T::zero());
+	let mut negative = false;
+	let mut len = 0;
+	let mut digits = 0;
+	loop {
+		let byte = match source.read_u8() {
+			Ok(byte) if byte == terminator => break,
+			Ok(byte) => byte,
+			Err(Error::End { .. }) if len == 0 => return Err(Error::end(1, 0)),
+			Err(Error::End { .. }) => break,
+			Err(error) => return Err(error)
+		};
+		len += 1;
+
+		match byte {
+			b'+' if len == 1 => { }
+			b'-' if len == 1 && signed => negative = true,
+			b'0'..=b'9' => {
+				digits += 1;
+				let digit = T::from(byte - b'0').expect("a digit should fit in an integer type");
+				// Accumulate negative values downward, so the minimum value can be
+				// parsed without overflowing. Keep reading once overflowed, to consume
+				// the text through the terminator.
+				value = value.and_then(|value| value.checked_mul(&ten)).and_then(|value|
+					if negative {
+						value.checked_sub(&digit)
+					} else {
+						value.checked_add(&digit)
+					}
+				);
+			}
+			_ => return Err(Error::parse_int(InvalidDigit))
+		}
+	}
+
+	match value {
+		_ if len == 0 => Err(Error::parse_int(Empty)),
+		_ if digits == 0 => Err(Error::parse_int(InvalidDigit)),
+		Some(value) => Ok(value),
+		None if negative => Err(Error::parse_int(NegOverflow)),
+		None => Err(Error::parse_int(PosOverflow)),
+	}
 }
 
 pub(crate) fn default_read_array<const N: usize>(source: &mut (impl DataSource + ?Sized)) -> Result<[u8; N]> {
@@ -675,18 +2207,24 @@ pub(crate) fn default_read_array<const N: usize>(source: &mut (impl DataSource +
 	Ok(array)
 }
 
+/// Calls [`DataSource::read_bytes`] until `buf` is filled or no bytes are read,
+/// returning the bytes read. `read_bytes` should be greedy, but a simple
+/// implementation making one read from its stream may return fewer bytes than it
+/// could.
+pub(crate) fn read_bytes_greedy<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut [u8]) -> Result<&'a [u8]> {
+	let mut count = 0;
+	while count < buf.len() {
+		let read_count = source.read_bytes(&mut buf[count..])?.len();
+		if read_count == 0 {
+			break
+		}
+		count += read_count;
+	}
+	Ok(&buf[..count])
+}
+
 fn try_read_exact_contiguous<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut [u8]) -> Result<&'a [u8]> {
-	let len = buf.len();
-	let bytes = source.read_bytes(buf)?;
-	assert_eq!(
-		bytes.len(),
-		len,
-		"read_bytes should be greedy; at least {available} bytes were available \
-		in the buffer, but only {read_len} bytes of the required {len} were read",
-		available = source.available(),
-		read_len = bytes.len()
-	);
-	Ok(bytes)
+	try_read_exact_discontiguous(source, buf, buf.len())
 }
 
 fn try_read_exact_discontiguous<'a>(
@@ -695,15 +2233,10 @@ fn try_read_exact_discontiguous<'a>(
 	remaining: usize
 ) -> Result<&'a [u8]> {
 	let filled = buf.len() - remaining;
-	let read_count = source.read_bytes(&mut buf[filled..])?.len();
+	let read_count = read_bytes_greedy(source, &mut buf[filled..])?.len();
 	if read_count < remaining {
-		if source.available() < remaining {
-			// Buffer was exhausted, meaning the stream ended prematurely
-			Err(Error::End { required_count: buf.len() })
-		} else {
-			// read_bytes wasn't greedy, there were enough bytes in the buffer >:(
-			panic!("read_bytes should have read {remaining} buffered bytes")
-		}
+		// The stream ended prematurely
+		Err(Error::end(buf.len(), filled + read_count))
 	} else {
 		// The whole slice has been confirmed to be filled.
 		Ok(buf)
@@ -711,6 +2244,11 @@ fn try_read_exact_discontiguous<'a>(
 }
 
 fn default_read_exact_bytes<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut [u8]) -> Result<&'a [u8]> {
+	if buf.is_empty() {
+		// Don't touch the stream, which could block.
+		return Ok(buf)
+	}
+
 	let len = buf.len();
 	match source.require(len) {
 		Ok(()) => try_read_exact_contiguous(source, buf),
@@ -726,7 +2264,7 @@ fn default_read_exact_bytes<'a>(source: &mut (impl DataSource + ?Sized), buf: &'
 }
 
 fn default_read_aligned_bytes<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut [u8], alignment: usize) -> Result<&'a [u8]> {
-	if alignment == 0 {
+	if alignment == 0 || buf.is_empty() {
 		return Ok(&[])
 	}
 	
@@ -745,6 +2283,10 @@ fn default_read_aligned_bytes<'a>(source: &mut (impl DataSource + ?Sized), buf:
 
 #[cfg(feature = "unstable_specialization")]
 fn buf_read_exact_bytes<'a>(source: &mut (impl BufferAccess + ?Sized), buf: &'a mut [u8]) -> Result<&'a [u8]> {
+	if buf.is_empty() {
+		return Ok(buf)
+	}
+
 	let len = buf.len();
 	match source.require(len) {
 		Ok(()) => try_read_exact_contiguous(source, buf),
@@ -797,6 +2339,167 @@ fn buf_read_bytes<'a>(
 	Ok(&buf[..filled])
 }
 
+/// Reads a value into uninitialized memory with [`DataSource::read_into_uninit`],
+/// skipping the zero-fill needed to read through [`DataSource::read_exact_bytes`]
+/// once the bytes are known to be available.
+fn default_read_data<T: Pod>(source: &mut (impl DataSource + ?Sized)) -> Result<T> {
+	let size = size_of::<T>();
+	if size == 0 {
+		return Ok(T::zeroed())
+	}
+
+	if source.available() < size {
+		match source.require(size) {
+			Ok(()) => { }
+			// Without a buffer to hold the value, or if the stream may end first,
+			// read exactly. Some sources override this to read across internal
+			// boundaries.
+			Err(Error::End { .. } | Error::InsufficientBuffer { .. }) => {
+				let mut value = T::zeroed();
+				source.read_exact_bytes(bytes_of_mut(&mut value))?;
+				return Ok(value)
+			}
+			Err(error) => return Err(error)
+		}
+	}
+
+	let mut value = MaybeUninit::<T>::uninit();
+	let bytes = unsafe {
+		// Safety: the value's memory may be viewed as uninitialized bytes of the
+		// same size.
+		core::slice::from_raw_parts_mut(value.as_mut_ptr().cast::<MaybeUninit<u8>>(), size)
+	};
+	let mut filled = 0;
+	while filled < size {
+		let count = read_into_spare(source, &mut bytes[filled..])?;
+		if count == 0 {
+			return Err(Error::end(size, filled))
+		}
+		filled += count;
+	}
+	Ok(unsafe {
+		// Safety: every byte was initialized by the reads, and T supports any bit
+		// pattern.
+		value.assume_init()
+	})
+}
+
+/// Reads a value directly out of the buffer, skipping the zero-fill needed to read
+/// through [`DataSource::read_exact_bytes`].
+#[cfg(feature = "unstable_specialization")]
+fn buf_read_data<T: Pod>(source: &mut (impl BufferAccess + ?Sized)) -> Result<T> {
+	let size = size_of::<T>();
+	if size == 0 {
+		return Ok(T::zeroed())
+	}
+
+	match source.require(size) {
+		Ok(()) => { }
+		Err(Error::InsufficientBuffer { .. }) => return default_read_data(source),
+		Err(error) => return Err(error)
+	}
+
+	// The buffer may not be contiguous, in which case fall back to the exact read.
+	let Some(bytes) = source.buffer().get(..size) else {
+		return default_read_data(source)
+	};
+	let value = bytemuck::pod_read_unaligned(bytes);
+	source.drain_buffer(size);
+	Ok(value)
+}
+
+fn default_read_into_uninit<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut [MaybeUninit<u8>]) -> Result<&'a [u8]> {
+	#[cfg(feature = "unstable_borrowed_buf")]
+	let count = {
+		let mut borrowed = core::io::BorrowedBuf::from(&mut *buf);
+		source.read_into_borrowed(&mut borrowed.unfilled())?;
+		borrowed.len()
+	};
+
+	#[cfg(not(feature = "unstable_borrowed_buf"))]
+	let count = {
+		buf.fill(MaybeUninit::new(0));
+		let buf = unsafe {
+			// Safety: all bytes have been initialized above, and MaybeUninit<u8> has
+			// the same layout as u8.
+			&mut *(core::ptr::from_mut(&mut *buf) as *mut [u8])
+		};
+		source.read_bytes(buf)?.len()
+	};
+
+	Ok(unsafe {
+		// Safety: the first count bytes were initialized by the read.
+		&*(core::ptr::from_ref(&buf[..count]) as *const [u8])
+	})
+}
+
+/// Reads into spare memory, such as the spare capacity of a vector, with
+/// [`DataSource::read_into_uninit`], returning the number of bytes initialized
+/// from the start of `spare`.
+///
+/// # Panics
+///
+/// Panics if the returned slice isn't the start of `spare`, as the memory can't be
+/// assumed initialized from a faulty implementation's slice.
+pub(crate) fn read_into_spare(source: &mut (impl DataSource + ?Sized), spare: &mut [MaybeUninit<u8>]) -> Result<usize> {
+	let start = spare.as_ptr().cast::<u8>();
+	let capacity = spare.len();
+	let filled = source.read_into_uninit(spare)?;
+	assert!(
+		filled.is_empty() || core::ptr::eq(filled.as_ptr(), start) && filled.len() <= capacity,
+		"read_into_uninit should return the start of its buffer"
+	);
+	Ok(filled.len())
+}
+
+#[cfg(feature = "unstable_borrowed_buf")]
+fn default_read_into_borrowed(source: &mut (impl DataSource + ?Sized), cursor: &mut BorrowedCursor<'_>) -> Result<usize> {
+	let unfilled = unsafe {
+		// Safety: uninitialized bytes are never written to the cursor.
+		cursor.as_mut()
+	};
+	unfilled.fill(core::mem::MaybeUninit::new(0));
+	let unfilled = unsafe {
+		// Safety: all bytes have been initialized above, and MaybeUninit<u8> has the
+		// same layout as u8.
+		&mut *(core::ptr::from_mut(unfilled) as *mut [u8])
+	};
+	let start = unfilled.as_ptr();
+	let capacity = unfilled.len();
+	let read = source.read_bytes(unfilled)?;
+	let count = read.len();
+	assert!(
+		count == 0 || core::ptr::eq(read.as_ptr(), start) && count <= capacity,
+		"read_bytes should return the start of its buffer"
+	);
+	unsafe {
+		// Safety: the bytes read were checked to be within the unfilled portion,
+		// all of which was initialized above.
+		cursor.advance(count);
+	}
+	Ok(count)
+}
+
+#[cfg(all(feature = "unstable_borrowed_buf", feature = "unstable_specialization"))]
+fn buf_read_into_borrowed(source: &mut (impl BufferAccess + ?Sized), cursor: &mut BorrowedCursor<'_>) -> Result<usize> {
+	let start = cursor.written();
+	while cursor.capacity() > 0 {
+		let buf = match source.request(cursor.capacity()) {
+			Ok(_) => source.buffer(),
+			Err(Error::InsufficientBuffer { .. }) => source.fill_buffer()?,
+			Err(error) => return Err(error)
+		};
+		if buf.is_empty() {
+			break
+		}
+
+		let count = buf.len().min(cursor.capacity());
+		cursor.append(&buf[..count]);
+		source.drain_buffer(count);
+	}
+	Ok(cursor.written() - start)
+}
+
 #[cfg(all(feature = "alloc", feature = "utf8"))]
 #[allow(dead_code)]
 pub(crate) fn default_read_utf8<'a>(
@@ -824,26 +2527,26 @@ fn default_read_utf8_codepoint<'a>(source: &mut (impl DataSource + ?Sized), buf:
 	Ok(from_utf8(&buf[..char_width])?)
 }
 
-#[cfg(feature = "unstable_ascii_char")]
-fn default_read_ascii<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut [u8]) -> Result<&'a [ascii::Char]> {
+fn default_read_ascii_bytes<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut [u8]) -> Result<&'a [u8]> {
 	let bytes = source.read_bytes(buf)?;
 	let idx = count_ascii(bytes);
 	if idx == bytes.len() {
-		// Safety: all bytes have been checked as valid ASCII.
-		Ok(unsafe { bytes.as_ascii_unchecked() })
+		Ok(bytes)
 	} else {
 		Err(Error::invalid_ascii(bytes[idx], idx, bytes.len()))
 	}
 }
 
 #[cfg(feature = "unstable_ascii_char")]
+fn default_read_ascii<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut [u8]) -> Result<&'a [ascii::Char]> {
+	let bytes = default_read_ascii_bytes(source, buf)?;
+	// Safety: all bytes have been checked as valid ASCII.
+	Ok(unsafe { bytes.as_ascii_unchecked() })
+}
+
+/// Returns the length of the valid ASCII prefix of `slice`.
 pub(crate) fn count_ascii(slice: &[u8]) -> usize {
-	if slice.is_ascii() {
-		slice.len()
-	} else {
-		// Safety: is_ascii indicates there is a non-ASCII character somewhere.
-		unsafe { slice.iter().rposition(|b| !b.is_ascii()).unwrap_unchecked() }
-	}
+	slice.iter().position(|b| !b.is_ascii()).unwrap_or(slice.len())
 }
 
 #[cfg(all(feature = "alloc", feature = "utf8"))]
@@ -993,6 +2696,30 @@ mod read_aligned_test {
 		}
 	}
 	
+	struct UnalignedSource;
+
+	impl DataSource for UnalignedSource {
+		fn available(&self) -> usize { 3 }
+		fn request(&mut self, count: usize) -> crate::Result<bool> { Ok(count <= 3) }
+		fn skip(&mut self, count: usize) -> crate::Result<usize> { Ok(count.min(3)) }
+		fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> crate::Result<&'a [u8]> {
+			Ok(&buf[..3])
+		}
+		fn read_aligned_bytes<'a>(&mut self, buf: &'a mut [u8], _: usize) -> crate::Result<&'a [u8]> {
+			self.read_bytes(buf)
+		}
+	}
+
+	#[test]
+	#[cfg_attr(debug_assertions, should_panic = "unaligned read implementation")]
+	fn read_data_slice_unaligned() {
+		use crate::{Error, GenericDataSource};
+		assert_eq!(
+			UnalignedSource.read_data_slice(&mut [0u16; 4]),
+			Err(Error::misaligned(3, 2))
+		);
+	}
+
 	proptest! {
 		#[test]
 		fn read_aligned_truncated(buf_size in 0usize..=15, alignment in 1usize..=16) {
@@ -1003,3 +2730,283 @@ mod read_aligned_test {
 		}
 	}
 }
+
+#[cfg(all(test, feature = "alloc"))]
+mod grow_buffer_test {
+	use alloc::vec::Vec;
+	use crate::{BufferAccess, DataSource, Result};
+
+	/// A source over a slice, buffering it in a growable vector.
+	struct GrowableSource {
+		data: &'static [u8],
+		buf: Vec<u8>,
+		pos: usize,
+		capacity: usize,
+	}
+
+	impl DataSource for GrowableSource {
+		#[cfg(not(feature = "unstable_specialization"))]
+		fn available(&self) -> usize { self.buffer_count() }
+
+		#[cfg(not(feature = "unstable_specialization"))]
+		fn request(&mut self, count: usize) -> Result<bool> {
+			super::default_request(self, count)
+		}
+
+		fn skip(&mut self, count: usize) -> Result<usize> {
+			let count = count.min(self.buffer_count());
+			self.drain_buffer(count);
+			Ok(count)
+		}
+
+		fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+			let bytes = self.fill_buffer()?;
+			let count = bytes.len().min(buf.len());
+			buf[..count].copy_from_slice(&bytes[..count]);
+			self.drain_buffer(count);
+			Ok(&buf[..count])
+		}
+	}
+
+	impl BufferAccess for GrowableSource {
+		fn buffer_capacity(&self) -> usize { self.capacity }
+
+		fn buffer(&self) -> &[u8] { &self.buf[self.pos..] }
+
+		fn fill_buffer(&mut self) -> Result<&[u8]> {
+			self.buf.drain(..self.pos);
+			self.pos = 0;
+			let count = (self.capacity - self.buf.len()).min(self.data.len());
+			self.buf.extend_from_slice(&self.data[..count]);
+			self.data = &self.data[count..];
+			Ok(&self.buf)
+		}
+
+		fn grow_buffer(&mut self, min_capacity: usize) -> Result {
+			self.capacity = self.capacity.max(min_capacity);
+			Ok(())
+		}
+
+		fn drain_buffer(&mut self, count: usize) {
+			self.pos += count;
+		}
+	}
+
+	#[test]
+	fn request_grows_buffer() -> Result {
+		let mut source = GrowableSource {
+			data: b"Hello, World!",
+			buf: Vec::new(),
+			pos: 0,
+			capacity: 2,
+		};
+		assert!(source.request(8)?);
+		assert_eq!(source.buffer(), b"Hello, W");
+		assert!(source.buffer_capacity() >= 8);
+		Ok(())
+	}
+
+	/// An in-memory source with all its data buffered, which can't grow.
+	struct InMemorySource(&'static [u8]);
+
+	impl DataSource for InMemorySource {
+		#[cfg(not(feature = "unstable_specialization"))]
+		fn available(&self) -> usize { self.buffer_count() }
+
+		fn remaining(&self) -> Option<u64> { Some(self.0.len() as u64) }
+
+		#[cfg(not(feature = "unstable_specialization"))]
+		fn request(&mut self, count: usize) -> Result<bool> {
+			super::default_request(self, count)
+		}
+
+		fn skip(&mut self, count: usize) -> Result<usize> {
+			let count = count.min(self.0.len());
+			self.drain_buffer(count);
+			Ok(count)
+		}
+
+		fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+			self.0.read_bytes(buf)
+		}
+	}
+
+	impl BufferAccess for InMemorySource {
+		fn buffer_capacity(&self) -> usize { self.0.len() }
+
+		fn buffer(&self) -> &[u8] { self.0 }
+
+		fn fill_buffer(&mut self) -> Result<&[u8]> { Ok(self.0) }
+
+		fn drain_buffer(&mut self, count: usize) {
+			self.0 = &self.0[count..];
+		}
+	}
+
+	#[test]
+	fn request_past_remaining() -> Result {
+		let mut source = InMemorySource(b"Hello");
+		assert!(source.request(5)?);
+		assert!(!source.request(6)?);
+		assert_eq!(source.require(6), Err(crate::Error::end(6, 0)));
+		source.skip(2)?;
+		assert!(!source.request(4)?);
+		Ok(())
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod empty_read_test {
+	use crate::{DataSource, GenericDataSource, Result};
+
+	/// A source which panics if the stream is touched.
+	struct UntouchedSource;
+
+	impl DataSource for UntouchedSource {
+		fn available(&self) -> usize { 0 }
+		fn request(&mut self, _: usize) -> Result<bool> { panic!("stream requested") }
+		fn skip(&mut self, _: usize) -> Result<usize> { panic!("stream skipped") }
+		fn read_bytes<'a>(&mut self, _: &'a mut [u8]) -> Result<&'a [u8]> {
+			panic!("stream read")
+		}
+	}
+
+	#[test]
+	fn empty_reads() -> Result {
+		assert_eq!(UntouchedSource.read_exact_bytes(&mut [])?, []);
+		assert_eq!(UntouchedSource.read_aligned_bytes(&mut [], 4)?, []);
+		assert_eq!(GenericDataSource::<u32>::read_data_slice(&mut UntouchedSource, &mut [])?, []);
+		Ok(())
+	}
+
+	#[test]
+	fn zero_sized_reads() -> Result {
+		let mut source = UntouchedSource;
+		GenericDataSource::<()>::read_data(&mut source)?;
+		assert_eq!(GenericDataSource::<()>::read_data_array::<3>(&mut source)?, [(); 3]);
+		assert_eq!(GenericDataSource::<()>::read_data_slice(&mut source, &mut [(); 4])?, [(); 4]);
+		assert_eq!(GenericDataSource::<()>::try_read_data_slice(&mut source, &mut [0; 4])?, []);
+		Ok(())
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod non_greedy_test {
+	use crate::{DataSource, Error, Result};
+
+	/// A source reading at most one byte per call, like a single read syscall.
+	struct NonGreedySource<'a>(&'a [u8]);
+
+	impl DataSource for NonGreedySource<'_> {
+		fn available(&self) -> usize { self.0.len() }
+		fn request(&mut self, count: usize) -> Result<bool> { Ok(self.0.len() >= count) }
+		fn skip(&mut self, count: usize) -> Result<usize> { self.0.skip(count) }
+		fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+			let len = buf.len().min(1);
+			self.0.read_bytes(&mut buf[..len])
+		}
+	}
+
+	#[test]
+	fn read_exact() -> Result {
+		let mut source = NonGreedySource(&[0xCA, 0xFE, 0xBA, 0xBE, 1, 2]);
+		assert_eq!(source.read_u32()?, 0xCAFE_BABE);
+		assert_eq!(source.read_exact_bytes(&mut [0; 4]), Err(Error::end(4, 0)));
+		Ok(())
+	}
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod read_into_spare_test {
+	use core::mem::MaybeUninit;
+	use crate::{DataSource, Result, VecSource};
+
+	/// Returns initialized bytes from elsewhere without writing to the buffer.
+	struct Misreporting;
+
+	impl DataSource for Misreporting {
+		fn available(&self) -> usize { 4 }
+		fn request(&mut self, _: usize) -> Result<bool> { Ok(true) }
+		fn skip(&mut self, count: usize) -> Result<usize> { Ok(count) }
+		fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> { Ok(buf) }
+		fn read_into_uninit<'a>(&mut self, _: &'a mut [MaybeUninit<u8>]) -> Result<&'a [u8]> {
+			Ok(&[0xAA; 4])
+		}
+	}
+
+	impl VecSource for Misreporting {
+		fn read_to_end<'a>(&mut self, _: &'a mut alloc::vec::Vec<u8>) -> Result<&'a [u8]> {
+			unimplemented!()
+		}
+
+		#[cfg(feature = "utf8")]
+		fn read_utf8_to_end<'a>(&mut self, _: &'a mut alloc::string::String) -> Result<&'a str> {
+			unimplemented!()
+		}
+	}
+
+	#[test]
+	#[should_panic = "read_into_uninit should return the start of its buffer"]
+	fn read_into_vec_checks_slice() {
+		let mut buf = alloc::vec::Vec::new();
+		let _ = Misreporting.read_into_vec(&mut buf, 4);
+	}
+}
+
+#[cfg(all(test, feature = "alloc", feature = "utf8"))]
+mod read_utf8_into_test {
+	use crate::{DataSource, Result};
+
+	/// Fills the buffer with invalid bytes, but returns a valid string from
+	/// elsewhere.
+	struct Misreporting;
+
+	impl DataSource for Misreporting {
+		fn available(&self) -> usize { 4 }
+		fn request(&mut self, _: usize) -> Result<bool> { Ok(true) }
+		fn skip(&mut self, count: usize) -> Result<usize> { Ok(count) }
+		fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+			buf.fill(0xFF);
+			Ok(buf)
+		}
+		fn read_utf8<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a str> {
+			buf.fill(0xFF);
+			Ok("abcd")
+		}
+	}
+
+	#[test]
+	fn unvalidated_bytes_discarded() -> Result {
+		let mut buf = alloc::string::String::from("> ");
+		Misreporting.read_utf8_into(4, &mut buf)?;
+		assert_eq!(buf, "> ");
+		assert!(core::str::from_utf8(buf.as_bytes()).is_ok());
+		Ok(())
+	}
+}
+
+#[cfg(all(test, feature = "unstable_borrowed_buf"))]
+mod read_into_borrowed_test {
+	use core::io::BorrowedBuf;
+	use crate::{DataSource, Result};
+
+	/// Returns bytes from elsewhere rather than those read into the buffer.
+	struct Misreporting;
+
+	impl DataSource for Misreporting {
+		fn available(&self) -> usize { 4 }
+		fn request(&mut self, _: usize) -> Result<bool> { Ok(true) }
+		fn skip(&mut self, count: usize) -> Result<usize> { Ok(count) }
+		fn read_bytes<'a>(&mut self, _: &'a mut [u8]) -> Result<&'a [u8]> {
+			Ok(&[0xAA; 16])
+		}
+	}
+
+	#[test]
+	#[should_panic = "read_bytes should return the start of its buffer"]
+	fn misreported_bytes() {
+		let mut bytes = [0; 4];
+		let mut buf = BorrowedBuf::from(&mut bytes[..]);
+		let _ = Misreporting.read_into_borrowed(&mut buf.unfilled());
+	}
+}
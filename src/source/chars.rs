@@ -0,0 +1,52 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "utf8")]
+
+use simdutf8::compat::from_utf8;
+use crate::{DataSource, Error, Result};
+use crate::utf8::utf8_char_width;
+
+/// An iterator decoding UTF-8 codepoints from a source, one at a time. Created by
+/// [`DataSource::chars`].
+#[derive(Clone, Debug)]
+pub struct Chars<S> {
+	source: S,
+}
+
+impl<S> Chars<S> {
+	pub(crate) const fn new(source: S) -> Self {
+		Self { source }
+	}
+
+	/// Consumes the iterator, returning the underlying source.
+	pub fn into_inner(self) -> S { self.source }
+}
+
+impl<S: DataSource> Iterator for Chars<S> {
+	type Item = Result<char>;
+
+	fn next(&mut self) -> Option<Result<char>> {
+		let mut buf = [0; 4];
+		match self.source.read_bytes(&mut buf[..1]) {
+			Ok([]) => return None,
+			Ok(_) => { }
+			Err(error) => return Some(Err(error))
+		}
+
+		// Invalid leading bytes have zero width; validate them on their own.
+		let char_width = utf8_char_width(buf[0]).max(1);
+		if let Err(error) = self.source.read_exact_bytes(&mut buf[1..char_width]) {
+			return Some(Err(match error {
+				Error::End { read_count, .. } => Error::end(char_width, read_count + 1),
+				error => error
+			}))
+		}
+
+		Some(
+			from_utf8(&buf[..char_width])
+				.map(|str| str.chars().next().unwrap())
+				.map_err(Error::from)
+		)
+	}
+}
@@ -0,0 +1,163 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{BufferAccess, DataSource, Error, Escape, Result};
+
+/// A source adapter reversing the escaping of an [`EscapingSink`], reading one
+/// frame at a time. Reading stops at an unescaped delimiter, as if the stream had
+/// ended; [`next_frame`] consumes it to continue with the following frame.
+///
+/// Escape bytes followed by an unknown substitute are read as the substitute.
+///
+/// ```
+/// # use data_streams::{DataSource, Escape, UnescapingSource};
+/// let mut source = UnescapingSource::new(&[1, 0xDB, 0xDC, 2, 0xC0, 3][..], Escape::SLIP);
+/// assert_eq!(source.read_bytes(&mut [0; 8])?, [1, 0xC0, 2]);
+/// assert!(source.next_frame());
+/// assert_eq!(source.read_bytes(&mut [0; 8])?, [3]);
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// [`EscapingSink`]: crate::EscapingSink
+/// [`next_frame`]: Self::next_frame
+#[derive(Clone, Debug)]
+pub struct UnescapingSource<S> {
+	source: S,
+	escape: Escape,
+	at_delimiter: bool,
+}
+
+impl<S> UnescapingSource<S> {
+	/// Creates an adapter unescaping bytes read from `source`.
+	pub const fn new(source: S, escape: Escape) -> Self {
+		Self { source, escape, at_delimiter: false }
+	}
+
+	/// Returns the escaping scheme.
+	pub const fn escape(&self) -> Escape { self.escape }
+
+	/// Returns a reference to the inner source.
+	pub const fn get_ref(&self) -> &S { &self.source }
+
+	/// Returns a mutable reference to the inner source.
+	pub fn get_mut(&mut self) -> &mut S { &mut self.source }
+
+	/// Consumes the adapter, returning the inner source. If reading stopped at a
+	/// delimiter, it remains unconsumed.
+	pub fn into_inner(self) -> S { self.source }
+}
+
+impl<S: BufferAccess> UnescapingSource<S> {
+	/// Consumes the delimiter ending the current frame, if reading has reached it,
+	/// and returns whether it was consumed.
+	pub fn next_frame(&mut self) -> bool {
+		let at_delimiter = self.at_delimiter;
+		if at_delimiter {
+			self.source.drain_buffer(1);
+			self.at_delimiter = false;
+		}
+		at_delimiter
+	}
+}
+
+impl<S: BufferAccess> DataSource for UnescapingSource<S> {
+	/// Returns the number of unescaped bytes in the inner source's buffer, up to the
+	/// next delimiter.
+	fn available(&self) -> usize {
+		if self.at_delimiter {
+			return 0
+		}
+
+		let buf = self.source.buffer();
+		let mut count = 0;
+		let mut i = 0;
+		while let Some(&byte) = buf.get(i) {
+			if byte == self.escape.delimiter() {
+				break
+			}
+
+			i += if byte == self.escape.escape() { 2 } else { 1 };
+			if i > buf.len() {
+				break
+			}
+			count += 1;
+		}
+		count
+	}
+
+	fn request(&mut self, count: usize) -> Result<bool> {
+		self.source.request(count)?;
+		Ok(self.available() >= count)
+	}
+
+	fn skip(&mut self, count: usize) -> Result<usize> {
+		let mut buf = [0; 64];
+		let mut skipped = 0;
+		while skipped < count {
+			let len = buf.len().min(count - skipped);
+			let read = self.read_bytes(&mut buf[..len])?.len();
+			if read == 0 {
+				break
+			}
+			skipped += read;
+		}
+		Ok(skipped)
+	}
+
+	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		let mut count = 0;
+		while count < buf.len() && !self.at_delimiter {
+			if self.source.buffer().is_empty() && self.source.fill_buffer()?.is_empty() {
+				break
+			}
+
+			let src = self.source.buffer();
+			let mut i = 0;
+			let mut split_escape = false;
+			while let Some(&byte) = src.get(i) {
+				if count == buf.len() {
+					break
+				}
+
+				if byte == self.escape.delimiter() {
+					self.at_delimiter = true;
+					break
+				}
+
+				if byte == self.escape.escape() {
+					let Some(&substitute) = src.get(i + 1) else {
+						split_escape = true;
+						break
+					};
+					buf[count] = self.escape.unsubstitute(substitute);
+					i += 2;
+				} else {
+					buf[count] = byte;
+					i += 1;
+				}
+				count += 1;
+			}
+			self.source.drain_buffer(i);
+
+			if split_escape {
+				// The substitute is past the end of the buffer.
+				self.source.drain_buffer(1);
+				let mut substitute = [0];
+				self.source.read_exact_bytes(&mut substitute)?;
+				buf[count] = self.escape.unsubstitute(substitute[0]);
+				count += 1;
+			}
+		}
+		Ok(&buf[..count])
+	}
+
+	fn read_exact_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		let len = buf.len();
+		let count = self.read_bytes(buf)?.len();
+		if count == len {
+			Ok(buf)
+		} else {
+			Err(Error::end(len, count))
+		}
+	}
+}
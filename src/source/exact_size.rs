@@ -4,9 +4,7 @@
 use core::ops::Deref;
 #[cfg(feature = "utf8")]
 use simdutf8::compat::from_utf8;
-use crate::{BufferAccess, DataSource, Result};
-#[cfg(feature = "unstable_ascii_char")]
-use crate::Error;
+use crate::{BufferAccess, DataSource, Error, Result};
 use crate::markers::source::SourceSize;
 use crate::source::max_multiple_of;
 
@@ -36,6 +34,7 @@ macro_rules! impl_source {
 		$(#[$meta])?
 		impl DataSource for $ty {
 			fn available(&self) -> usize { self.len() }
+			fn remaining(&self) -> Option<u64> { Some(self.len() as u64) }
 			fn request(&mut self, count: usize) -> Result<bool> {
 				Ok(self.len() >= count)
 			}
@@ -46,6 +45,16 @@ macro_rules! impl_source {
 				Ok(count)
 			}
 		
+			fn read_u8(&mut self) -> Result<u8> {
+				let &byte = self.first().ok_or(Error::end(1, 0))?;
+				self.consume(1);
+				Ok(byte)
+			}
+
+			fn read_i8(&mut self) -> Result<i8> {
+				self.read_u8().map(u8::cast_signed)
+			}
+
 			fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
 				Ok(self.read_bytes_infallible(buf))
 			}
@@ -53,6 +62,22 @@ macro_rules! impl_source {
 			fn read_aligned_bytes<'a>(&mut self, buf: &'a mut [u8], alignment: usize) -> Result<&'a [u8]> {
 				Ok(self.read_aligned_bytes_infallible(buf, alignment))
 			}
+
+			#[cfg(feature = "unstable_borrowed_buf")]
+			fn read_into_borrowed(&mut self, cursor: &mut core::io::BorrowedCursor<'_>) -> Result<usize> {
+				let count = self.len().min(cursor.capacity());
+				cursor.append(&self[..count]);
+				self.consume(count);
+				Ok(count)
+			}
+
+			#[cfg(feature = "unstable_uninit_slice")]
+			fn read_into_uninit<'a>(&mut self, buf: &'a mut [core::mem::MaybeUninit<u8>]) -> Result<&'a [u8]> {
+				let count = self.len().min(buf.len());
+				let filled = buf[..count].write_copy_of_slice(&self[..count]);
+				self.consume(count);
+				Ok(filled)
+			}
 		
 			/// Reads bytes into a slice, returning them as a UTF-8 string if valid.
 			///
@@ -78,32 +103,118 @@ macro_rules! impl_source {
 				result
 			}
 
-			/// Reads bytes into a slice, returning them as an ASCII slice if valid.
+			/// Reads bytes into a slice, returning them if valid ASCII.
 			///
 			/// # Errors
 			///
 			/// Returns [`Error::Ascii`] if a non-ASCII byte is found. This
 			/// implementation consumes only valid ASCII. `buf` is left with valid
 			/// ASCII bytes with a length of [`AsciiError::valid_up_to`]. The valid
-			/// slice can be retrieved with [`AsciiError::valid_slice`].
-			#[cfg(feature = "unstable_ascii_char")]
-			fn read_ascii<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [core::ascii::Char]> {
+			/// slice can be retrieved with [`AsciiError::valid_bytes`].
+			///
+			/// [`AsciiError::valid_up_to`]: crate::AsciiError::valid_up_to
+			/// [`AsciiError::valid_bytes`]: crate::AsciiError::valid_bytes
+			fn read_ascii_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
 				let len = buf.len().min(self.len());
 				let count = super::count_ascii(&self[..len]);
 				let bytes = self.read_bytes_infallible(&mut buf[..count]);
 				if count == len {
-					// Safety: all bytes have been checked as valid ASCII.
-					Ok(unsafe { bytes.as_ascii_unchecked() })
+					Ok(bytes)
 				} else {
 					Err(Error::invalid_ascii(self[0], count, count))
 				}
 			}
+
+			/// Reads bytes into a slice, returning them as an ASCII slice if valid.
+			///
+			/// # Errors
+			///
+			/// Returns [`Error::Ascii`] if a non-ASCII byte is found. This
+			/// implementation consumes only valid ASCII. `buf` is left with valid
+			/// ASCII bytes with a length of [`AsciiError::valid_up_to`]. The valid
+			/// slice can be retrieved with [`AsciiError::valid_slice`].
+			///
+			/// [`AsciiError::valid_up_to`]: crate::AsciiError::valid_up_to
+			/// [`AsciiError::valid_slice`]: crate::AsciiError::valid_slice
+			#[cfg(feature = "unstable_ascii_char")]
+			fn read_ascii<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [core::ascii::Char]> {
+				let bytes = self.read_ascii_bytes(buf)?;
+				// Safety: all bytes have been checked as valid ASCII.
+				Ok(unsafe { bytes.as_ascii_unchecked() })
+			}
 		})+
 	};
 }
 
 impl_source! { &[u8]; #[cfg(feature = "alloc")] alloc::vec::Vec<u8> }
 
+/// A source over bytes already in memory, which can be read without copying by
+/// borrowing sub-slices of the underlying data. The returned slices have the
+/// lifetime `'a` of the data itself rather than of the source, so they remain
+/// valid while the source is read further.
+///
+/// This is implemented for `&'a [u8]`, [`StrSource<'a>`], and `Cursor<&'a [u8]>`.
+/// Owned sources such as `Vec<u8>` can't lend out bytes they'll drop once consumed;
+/// read from a borrowed slice of them instead, such as [`Vec::as_slice`].
+///
+/// ```
+/// # use data_streams::{DataSource, SliceSource};
+/// let data = b"\x05Hello, World!";
+/// let mut source = &data[..];
+/// let len = source.read_u8()? as usize;
+/// let name = source.read_slice_exact(len)?;
+/// assert_eq!(source.read_slice_exact(2)?, b", ");
+/// assert_eq!(name, b"Hello");
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// [`Vec::as_slice`]: alloc::vec::Vec::as_slice
+pub trait SliceSource<'a>: DataSource {
+	/// Reads exactly `count` bytes, returning them as a slice of the underlying
+	/// data without copying.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::End`] if fewer than `count` bytes remain. Nothing is
+	/// consumed in this case, so the error's read count is always zero.
+	fn read_slice_exact(&mut self, count: usize) -> Result<&'a [u8]>;
+}
+
+impl<'a> SliceSource<'a> for &'a [u8] {
+	fn read_slice_exact(&mut self, count: usize) -> Result<&'a [u8]> {
+		if count > self.len() {
+			return Err(Error::end(count, 0))
+		}
+
+		let (slice, rest) = self.split_at(count);
+		*self = rest;
+		Ok(slice)
+	}
+}
+
+// The macro doesn't take generics, so the array source is implemented separately.
+impl<const N: usize> DataSource for ArraySource<N> {
+	fn available(&self) -> usize { self.len() }
+	fn remaining(&self) -> Option<u64> { Some(self.len() as u64) }
+	fn request(&mut self, count: usize) -> Result<bool> {
+		Ok(self.len() >= count)
+	}
+
+	fn skip(&mut self, mut count: usize) -> Result<usize> {
+		count = count.min(self.len());
+		self.consume(count);
+		Ok(count)
+	}
+
+	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		Ok(self.read_bytes_infallible(buf))
+	}
+
+	fn read_aligned_bytes<'a>(&mut self, buf: &'a mut [u8], alignment: usize) -> Result<&'a [u8]> {
+		Ok(self.read_aligned_bytes_infallible(buf, alignment))
+	}
+}
+
 impl ExactSizeBuffer for &[u8] {
 	fn consume(&mut self, count: usize) {
 		*self = &self[count..];
@@ -153,3 +264,212 @@ unsafe impl SourceSize for alloc::vec::Vec<u8> {
 	fn lower_bound(&self) -> u64 { self.len() as u64 }
 	fn upper_bound(&self) -> Option<u64> { Some(self.len() as u64) }
 }
+
+/// A source reading from an owned byte array, such as a header assembled on the
+/// stack. An array can't shrink like a slice, so the source tracks the offset of
+/// the next byte to read. It dereferences to the unread bytes.
+///
+/// ```
+/// # use data_streams::{ArraySource, DataSource};
+/// let mut source = ArraySource::new([0xCA, 0xFE, 0xBA, 0xBE, 1]);
+/// assert_eq!(source.read_u32()?, 0xCAFE_BABE);
+/// assert_eq!(source.offset(), 4);
+/// assert_eq!(source.read_u8()?, 1);
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ArraySource<const N: usize> {
+	array: [u8; N],
+	offset: usize,
+}
+
+impl<const N: usize> ArraySource<N> {
+	/// Creates a source reading from the start of `array`.
+	pub const fn new(array: [u8; N]) -> Self {
+		Self { array, offset: 0 }
+	}
+
+	/// Returns the number of bytes read from the array.
+	pub const fn offset(&self) -> usize { self.offset }
+
+	/// Returns a reference to the whole array, including bytes already read.
+	pub const fn get_ref(&self) -> &[u8; N] { &self.array }
+
+	/// Consumes the source, returning the array.
+	pub const fn into_inner(self) -> [u8; N] { self.array }
+}
+
+impl<const N: usize> From<[u8; N]> for ArraySource<N> {
+	fn from(array: [u8; N]) -> Self {
+		Self::new(array)
+	}
+}
+
+impl<const N: usize> Deref for ArraySource<N> {
+	type Target = [u8];
+
+	fn deref(&self) -> &[u8] { &self.array[self.offset..] }
+}
+
+impl<const N: usize> ExactSizeBuffer for ArraySource<N> {
+	fn consume(&mut self, count: usize) {
+		self.offset += count;
+	}
+}
+
+impl<const N: usize> BufferAccess for ArraySource<N> {
+	fn buffer_capacity(&self) -> usize { self.len() }
+
+	fn buffer(&self) -> &[u8] { self }
+
+	fn fill_buffer(&mut self) -> Result<&[u8]> { Ok(self) }
+
+	fn drain_buffer(&mut self, count: usize) {
+		assert!(count <= self.len(), "drain count exceeds the buffer length");
+		self.consume(count);
+	}
+}
+
+unsafe impl<const N: usize> SourceSize for ArraySource<N> {
+	fn lower_bound(&self) -> u64 { self.len() as u64 }
+	fn upper_bound(&self) -> Option<u64> { Some(self.len() as u64) }
+}
+
+/// A source reading the bytes of a string slice, such as text embedded in a larger
+/// binary format. It reads exactly like `&[u8]` over [`str::as_bytes`], and may be
+/// left between the bytes of a codepoint, so it dereferences to the unread bytes
+/// rather than a string.
+///
+/// As the string is already valid UTF-8, reading UTF-8 skips validation when the
+/// read starts and stops on character boundaries.
+///
+/// ```
+/// # use data_streams::{DataSource, StrSource};
+/// let mut source = StrSource::new("\x02Hi!");
+/// let len = source.read_u8()? as usize;
+/// assert_eq!(source.read_bytes(&mut [0; 8][..len])?, b"Hi");
+/// assert_eq!(source.as_str(), Some("!"));
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct StrSource<'a> {
+	bytes: &'a [u8],
+}
+
+impl<'a> StrSource<'a> {
+	/// Creates a source reading from the start of `value`.
+	pub const fn new(value: &'a str) -> Self {
+		Self { bytes: value.as_bytes() }
+	}
+
+	/// Returns the unread bytes as a string slice, or `None` if the source was left
+	/// between the bytes of a codepoint.
+	pub fn as_str(&self) -> Option<&'a str> {
+		if is_char_boundary(self.bytes, 0) {
+			// Safety: the bytes are a suffix of a valid string, starting on a
+			// character boundary.
+			Some(unsafe { core::str::from_utf8_unchecked(self.bytes) })
+		} else {
+			None
+		}
+	}
+}
+
+impl<'a> From<&'a str> for StrSource<'a> {
+	fn from(value: &'a str) -> Self {
+		Self::new(value)
+	}
+}
+
+impl Deref for StrSource<'_> {
+	type Target = [u8];
+
+	fn deref(&self) -> &[u8] { self.bytes }
+}
+
+impl ExactSizeBuffer for StrSource<'_> {
+	fn consume(&mut self, count: usize) {
+		self.bytes = &self.bytes[count..];
+	}
+}
+
+impl DataSource for StrSource<'_> {
+	fn available(&self) -> usize { self.len() }
+	fn remaining(&self) -> Option<u64> { Some(self.len() as u64) }
+	fn request(&mut self, count: usize) -> Result<bool> {
+		Ok(self.len() >= count)
+	}
+
+	fn skip(&mut self, mut count: usize) -> Result<usize> {
+		count = count.min(self.len());
+		self.consume(count);
+		Ok(count)
+	}
+
+	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		Ok(self.read_bytes_infallible(buf))
+	}
+
+	fn read_aligned_bytes<'a>(&mut self, buf: &'a mut [u8], alignment: usize) -> Result<&'a [u8]> {
+		Ok(self.read_aligned_bytes_infallible(buf, alignment))
+	}
+
+	/// Reads bytes into a slice, returning them as a UTF-8 string if valid. The
+	/// bytes are only validated if the read starts or stops between the bytes of a
+	/// codepoint.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Utf8`] if invalid UTF-8 is read. This implementation only
+	/// consumes valid UTF-8. `buf` is left with a valid UTF-8 string whose length
+	/// is given by the error, [`Utf8Error::valid_up_to`].
+	///
+	/// [`Error::Utf8`]: crate::Error::Utf8
+	/// [`Utf8Error::valid_up_to`]: simdutf8::compat::Utf8Error::valid_up_to
+	#[cfg(feature = "utf8")]
+	fn read_utf8<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a str> {
+		let count = buf.len().min(self.len());
+		let filled = &mut buf[..count];
+		filled.copy_from_slice(&self[..count]);
+		if is_char_boundary(self, 0) && is_char_boundary(self, count) {
+			self.consume(count);
+			// Safety: the bytes were copied from a valid string, between two
+			// character boundaries.
+			return Ok(unsafe { core::str::from_utf8_unchecked(filled) })
+		}
+
+		let (result, consumed) = match from_utf8(filled) {
+			Ok(str) => (Ok(str), count),
+			Err(error) => (Err(error.into()), error.valid_up_to())
+		};
+		self.consume(consumed);
+		result
+	}
+}
+
+impl<'a> SliceSource<'a> for StrSource<'a> {
+	fn read_slice_exact(&mut self, count: usize) -> Result<&'a [u8]> {
+		self.bytes.read_slice_exact(count)
+	}
+}
+
+impl BufferAccess for StrSource<'_> {
+	fn buffer_capacity(&self) -> usize { self.len() }
+
+	fn buffer(&self) -> &[u8] { self }
+
+	fn fill_buffer(&mut self) -> Result<&[u8]> { Ok(self) }
+
+	fn drain_buffer(&mut self, count: usize) { self.consume(count); }
+}
+
+unsafe impl SourceSize for StrSource<'_> {
+	fn lower_bound(&self) -> u64 { self.len() as u64 }
+	fn upper_bound(&self) -> Option<u64> { Some(self.len() as u64) }
+}
+
+/// Returns whether `index` is on a character boundary in `bytes`, a suffix of a
+/// valid UTF-8 string. Equivalent to [`str::is_char_boundary`].
+fn is_char_boundary(bytes: &[u8], index: usize) -> bool {
+	bytes.get(index).is_none_or(|byte| !(0x80..0xC0).contains(byte))
+}
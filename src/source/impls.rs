@@ -3,12 +3,11 @@
 
 #![cfg(all(feature = "alloc", feature = "unstable_specialization"))]
 
-use core::mem::MaybeUninit;
 use crate::BufferAccess;
 #[cfg(feature = "utf8")]
 use alloc::string::String;
 use alloc::vec::Vec;
-use super::{DataSource, markers::SourceSize, Result};
+use super::{DataSource, markers::{InfiniteSource, SourceSize}, Error, Result};
 
 #[cfg(feature = "utf8")]
 pub fn buf_read_utf8_to_end<'a>(source: &mut impl BufferAccess, buf: &'a mut String) -> Result<&'a str> {
@@ -20,6 +19,10 @@ pub fn buf_read_utf8_to_end<'a>(source: &mut impl BufferAccess, buf: &'a mut Str
 }
 
 pub fn buf_read_to_end<'a>(source: &mut impl BufferAccess, buf: &'a mut Vec<u8>) -> Result<&'a [u8]> {
+	if is_infinite(source) {
+		return Err(Error::NoEnd)
+	}
+
 	let start = buf.len();
 	// Drain then bypass the buffer. We'll use the vector as a buffer instead.
 	while source.buffer_count() > 0 {
@@ -61,6 +64,10 @@ pub fn read_to_end<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut Vec
 		Ok(!bytes.is_empty())
 	}
 
+	if is_infinite(source) {
+		return Err(Error::NoEnd)
+	}
+
 	let start_len = buf.len();
 	let start_cap = buf.capacity();
 	let size_hint = source.size_hint();
@@ -71,7 +78,6 @@ pub fn read_to_end<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut Vec
 		return Ok(&[])
 	}
 
-	let mut initialized = 0;
 	let mut chunk_size = size_hint.unwrap_or(min_chunk_size.max(CHUNK_SIZE));
 	loop {
 		if buf.len() == buf.capacity() && buf.capacity() == start_cap && !probe(source, buf)? {
@@ -82,27 +88,20 @@ pub fn read_to_end<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut Vec
 			buf.try_reserve(PROBE_SIZE)?;
 		}
 
-		let mut spare = buf.spare_capacity_mut();
+		let spare = buf.spare_capacity_mut();
 		let buf_len = spare.len().min(chunk_size as usize);
-		spare = &mut spare[..buf_len];
+		let spare = &mut spare[..buf_len];
 
-		spare[initialized..].fill(MaybeUninit::new(0));
-		let spare_init = unsafe {
-			// Safety: all uninitialized bytes have been initialized above, and
-			// MaybeUninit<u8> has the same layout as u8.
-			&mut *(core::ptr::from_mut::<[MaybeUninit<u8>]>(spare) as *mut [u8]) // Stable slice_assume_init_ref
-		};
-
-		let read = source.read_bytes(spare_init)?.len();
-		let empty_init = buf_len - read;
+		// Read directly into the uninitialized spare capacity, skipping the zero-
+		// fill for sources which support it.
+		let read = super::read_into_spare(source, spare)?;
 
 		if read == 0 {
 			break Ok(&buf[start_len..])
 		}
 
-		initialized = empty_init;
-
-		// Safety: this length was explicitly initialized above.
+		// Safety: read_into_spare checked that the first read bytes of the spare
+		// capacity were initialized.
 		unsafe {
 			buf.set_len(read + buf.len());
 		}
@@ -117,3 +116,21 @@ pub fn read_to_end<'a>(source: &mut (impl DataSource + ?Sized), buf: &'a mut Vec
 		}
 	}
 }
+
+/// Returns whether the source is marked as an [`InfiniteSource`], which would never
+/// reach its end.
+pub fn is_infinite<T: ?Sized>(source: &T) -> bool {
+	trait Infinite {
+		fn is_infinite(&self) -> bool;
+	}
+
+	impl<T: ?Sized> Infinite for T {
+		default fn is_infinite(&self) -> bool { false }
+	}
+
+	impl<T: InfiniteSource + ?Sized> Infinite for T {
+		fn is_infinite(&self) -> bool { true }
+	}
+
+	source.is_infinite()
+}
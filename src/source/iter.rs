@@ -0,0 +1,78 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{DataSource, Result};
+
+/// A source reading bytes from an iterator, for example to generate test streams or
+/// adapt producers which aren't byte-oriented.
+///
+/// The source doesn't implement [`SourceSize`]. Its bounds would come from the
+/// iterator's size hint, which safe code may report incorrectly, so it can't back
+/// an unsafe trait other code relies on for soundness.
+///
+/// ```
+/// # use data_streams::{DataSource, IterSource};
+/// let mut source = IterSource::new((1..=4).map(|i| i * 2));
+/// assert_eq!(source.available(), 4);
+/// assert_eq!(source.read_u16()?, 0x0204);
+/// assert_eq!(source.read_bytes(&mut [0; 4])?, [6, 8]);
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// [`SourceSize`]: crate::markers::source::SourceSize
+#[derive(Clone, Debug)]
+pub struct IterSource<I> {
+	iter: I,
+}
+
+impl<I> IterSource<I> {
+	/// Creates a source reading from `iter`.
+	pub const fn new(iter: I) -> Self {
+		Self { iter }
+	}
+
+	/// Returns a reference to the underlying iterator.
+	pub const fn get_ref(&self) -> &I { &self.iter }
+
+	/// Returns a mutable reference to the underlying iterator.
+	pub fn get_mut(&mut self) -> &mut I { &mut self.iter }
+
+	/// Consumes the source, returning the underlying iterator.
+	pub fn into_inner(self) -> I { self.iter }
+}
+
+impl<I: Iterator<Item = u8>> DataSource for IterSource<I> {
+	/// Returns the lower bound of the iterator's [size hint](Iterator::size_hint).
+	fn available(&self) -> usize { self.iter.size_hint().0 }
+
+	/// Returns the iterator's length if its [size hint](Iterator::size_hint) is
+	/// exact.
+	fn remaining(&self) -> Option<u64> {
+		match self.iter.size_hint() {
+			(lower, Some(upper)) if lower == upper => Some(lower as u64),
+			_ => None
+		}
+	}
+
+	/// Returns `false` only if the iterator's size hint rules out `count` bytes.
+	/// Bytes can't be pulled ahead of a read, so exact reads check the count of
+	/// bytes actually read instead.
+	fn request(&mut self, count: usize) -> Result<bool> {
+		Ok(self.iter.size_hint().1.is_none_or(|upper| upper >= count))
+	}
+
+	fn skip(&mut self, count: usize) -> Result<usize> {
+		// The size hint may be wrong, so count the bytes actually skipped.
+		Ok(self.iter.by_ref().take(count).count())
+	}
+
+	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		let mut count = 0;
+		// The slice must come first, to avoid pulling an extra byte once it's full.
+		for (dst, byte) in buf.iter_mut().zip(&mut self.iter) {
+			*dst = byte;
+			count += 1;
+		}
+		Ok(&buf[..count])
+	}
+}
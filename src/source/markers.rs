@@ -1,17 +1,57 @@
 // Copyright 2024 - Strixpyrr
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::DataSource;
+use crate::{DataSource, Result};
 
 /// A trait which marks a source as infinite, preventing "read-to-end" operations
 /// from completing.
+///
+/// An infinite source can always satisfy a skip or request, even if it only has a
+/// few bytes buffered. [`skip`] must consume exactly the byte count, and [`request`]
+/// must never return `false`, so that [`require`] never returns [`Error::End`].
+/// Sources which can't skip directly may delegate to [`infinite_skip`].
+///
+/// With the `unstable_specialization` feature, the default "read-to-end" methods of
+/// [`VecSource`] check for this marker, returning [`Error::NoEnd`] rather than
+/// reading forever.
 /// 
 /// # Safety
 /// 
 /// The source must be truly infinite; it must **always** produce data. An example
 /// in the standard library is [`std::io::Repeat`].
+///
+/// [`skip`]: DataSource::skip
+/// [`request`]: DataSource::request
+/// [`require`]: DataSource::require
+/// [`Error::End`]: crate::Error::End
+/// [`Error::NoEnd`]: crate::Error::NoEnd
+/// [`VecSource`]: crate::VecSource
 pub unsafe trait InfiniteSource: DataSource { }
 
+/// Skips exactly `count` bytes of an infinite source by reading and discarding
+/// them, returning `count`. An [`InfiniteSource`] may delegate [`DataSource::skip`]
+/// to this, rather than clamping to its available byte count.
+///
+/// # Errors
+///
+/// Returns any IO errors encountered.
+///
+/// # Panics
+///
+/// Panics if the source stops producing bytes, breaking the [`InfiniteSource`]
+/// contract.
+pub fn infinite_skip(source: &mut (impl InfiniteSource + ?Sized), count: usize) -> Result<usize> {
+	let mut scratch = [0; 256];
+	let mut remaining = count;
+	while remaining > 0 {
+		let len = remaining.min(scratch.len());
+		let read_count = source.read_bytes(&mut scratch[..len])?.len();
+		assert_ne!(read_count, 0, "infinite source stopped producing bytes");
+		remaining -= read_count;
+	}
+	Ok(count)
+}
+
 /// A trait which gives known upper and lower bounds of the size of the source.
 /// 
 /// # Safety
@@ -28,3 +68,66 @@ pub unsafe trait SourceSize {
 }
 
 unsafe impl<T: InfiniteSource> SourceSize for T { }
+
+#[cfg(test)]
+mod test {
+	use crate::{DataSource, Result};
+	use super::{infinite_skip, InfiniteSource};
+
+	/// An infinite source counting up from zero, buffering one byte at a time.
+	struct Counter(u8);
+
+	impl DataSource for Counter {
+		fn available(&self) -> usize { 1 }
+		fn request(&mut self, _: usize) -> Result<bool> { Ok(true) }
+		fn skip(&mut self, count: usize) -> Result<usize> { infinite_skip(self, count) }
+		fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+			let Some(byte) = buf.first_mut() else { return Ok(&[]) };
+			*byte = self.0;
+			self.0 = self.0.wrapping_add(1);
+			Ok(&buf[..1])
+		}
+	}
+
+	unsafe impl InfiniteSource for Counter { }
+
+	#[test]
+	fn skip_past_available() -> Result {
+		let mut source = Counter(0);
+		assert_eq!(source.skip(1000)?, 1000);
+		assert_eq!(source.read_u8()?, (1000 % 256) as u8);
+		source.require(4096)?;
+		Ok(())
+	}
+
+	#[cfg(all(feature = "alloc", feature = "unstable_specialization"))]
+	impl crate::VecSource for Counter { }
+
+	#[cfg(all(feature = "alloc", feature = "unstable_specialization"))]
+	#[test]
+	fn read_to_end_no_end() {
+		use crate::{Error, VecSource};
+
+		let mut source = Counter(0);
+		let mut buf = Vec::new();
+		assert_eq!(source.read_to_end(&mut buf), Err(Error::NoEnd));
+		assert_eq!(buf.capacity(), 0);
+		#[cfg(feature = "utf8")]
+		{
+			let mut buf = String::new();
+			assert_eq!(source.read_utf8_to_end(&mut buf), Err(Error::NoEnd));
+			assert_eq!(buf.capacity(), 0);
+		}
+		assert_eq!(source.read_u8(), Ok(0));
+	}
+
+	#[cfg(all(feature = "alloc", feature = "unstable_specialization"))]
+	#[test]
+	fn extend_from_source_no_end() {
+		use crate::{Error, ExtendFromSource};
+
+		let mut buf = Vec::new();
+		assert_eq!(buf.extend_from_source(&mut Counter(0)), Err(Error::NoEnd));
+		assert_eq!(buf.capacity(), 0);
+	}
+}
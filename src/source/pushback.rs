@@ -0,0 +1,143 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{BufferAccess, DataSource, Error, Result};
+
+/// A source adapter holding up to `N` bytes of lookahead from an inner source,
+/// which can be peeked at or pushed back after reading. This gives sources without
+/// a buffer, such as a raw reader or an [`IterSource`], [`BufferAccess`] for small
+/// lookahead without allocating.
+///
+/// ```
+/// # use data_streams::{BufferAccess, DataSource, IterSource, PushbackSource};
+/// let mut source = PushbackSource::<_, 4>::new(IterSource::new(b"GIF89a".iter().copied()));
+/// assert_eq!(source.try_peek_array()?, Some(*b"GIF"));
+/// let mut buf = [0; 3];
+/// source.read_exact_bytes(&mut buf)?;
+/// source.unread(&buf[1..])?;
+/// assert_eq!(source.read_bytes(&mut [0; 8])?, b"IF89a");
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// [`IterSource`]: crate::IterSource
+#[derive(Clone, Debug)]
+pub struct PushbackSource<S, const N: usize = 16> {
+	source: S,
+	buf: [u8; N],
+	pos: usize,
+	len: usize,
+}
+
+impl<S, const N: usize> PushbackSource<S, N> {
+	/// Creates a lookahead adapter over `source`.
+	pub const fn new(source: S) -> Self {
+		Self {
+			source,
+			buf: [0; N],
+			pos: 0,
+			len: 0,
+		}
+	}
+
+	/// Returns a reference to the inner source.
+	pub const fn get_ref(&self) -> &S { &self.source }
+
+	/// Returns a mutable reference to the inner source. Reading from it directly
+	/// skips any buffered bytes.
+	pub fn get_mut(&mut self) -> &mut S { &mut self.source }
+
+	/// Consumes the adapter, returning the inner source. Any buffered bytes are
+	/// lost.
+	pub fn into_inner(self) -> S { self.source }
+
+	/// Pushes `bytes` back to the front of the buffer, to be read again before the
+	/// buffered bytes.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::InsufficientBuffer`] if the buffer can't hold `bytes` in
+	/// addition to the bytes already buffered. Nothing is pushed back in this case.
+	pub fn unread(&mut self, bytes: &[u8]) -> Result {
+		let count = bytes.len();
+		let buf_len = self.len - self.pos;
+		if buf_len + count > N {
+			return Err(Error::insufficient_buffer(N - buf_len, count))
+		}
+
+		if self.pos < count {
+			self.buf.copy_within(self.pos..self.len, count);
+			self.pos = count;
+			self.len = count + buf_len;
+		}
+
+		self.pos -= count;
+		self.buf[self.pos..self.pos + count].copy_from_slice(bytes);
+		Ok(())
+	}
+}
+
+impl<S: DataSource, const N: usize> DataSource for PushbackSource<S, N> {
+	/// Returns the number of buffered bytes.
+	fn available(&self) -> usize { self.len - self.pos }
+
+	fn remaining(&self) -> Option<u64> {
+		self.source.remaining().map(|remaining| remaining + self.available() as u64)
+	}
+
+	fn request(&mut self, count: usize) -> Result<bool> {
+		if count > N {
+			self.grow_buffer(count)?;
+		}
+
+		while self.available() < count {
+			let buf_len = self.available();
+			if self.fill_buffer()?.len() == buf_len {
+				return Ok(false)
+			}
+		}
+		Ok(true)
+	}
+
+	fn skip(&mut self, count: usize) -> Result<usize> {
+		let buf_count = self.available().min(count);
+		self.drain_buffer(buf_count);
+		Ok(buf_count + self.source.skip(count - buf_count)?)
+	}
+
+	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		let buf_count = self.available().min(buf.len());
+		buf[..buf_count].copy_from_slice(&self.buf[self.pos..self.pos + buf_count]);
+		self.drain_buffer(buf_count);
+		let count = self.source.read_bytes(&mut buf[buf_count..])?.len();
+		Ok(&buf[..buf_count + count])
+	}
+}
+
+impl<S: DataSource, const N: usize> BufferAccess for PushbackSource<S, N> {
+	fn buffer_capacity(&self) -> usize { N }
+
+	fn buffer(&self) -> &[u8] { &self.buf[self.pos..self.len] }
+
+	/// Reads from the inner source into the spare buffer capacity, moving the
+	/// buffered bytes to the front first.
+	fn fill_buffer(&mut self) -> Result<&[u8]> {
+		if self.pos > 0 {
+			self.buf.copy_within(self.pos..self.len, 0);
+			self.len -= self.pos;
+			self.pos = 0;
+		}
+
+		let count = self.source.read_bytes(&mut self.buf[self.len..])?.len();
+		self.len += count;
+		Ok(&self.buf[..self.len])
+	}
+
+	fn drain_buffer(&mut self, count: usize) {
+		assert!(count <= self.available(), "cannot drain more bytes than are buffered");
+		self.pos += count;
+		if self.pos == self.len {
+			self.pos = 0;
+			self.len = 0;
+		}
+	}
+}
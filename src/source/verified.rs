@@ -0,0 +1,188 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "digest")]
+
+use digest::{Digest, Output};
+use crate::{DataSource, Error, Result};
+
+/// A source adapter which hashes bytes read from an inner source, verifying them
+/// against a digest trailing the data. The digest length is the output size of the
+/// hasher `H`.
+///
+/// To find the digest, the last bytes read from the inner source are held back
+/// until more data arrives, and are never fed into the hasher. When the inner
+/// source ends, these are compared against the hash of the data. The digest can
+/// also be checked before the inner source ends with [`finish`].
+///
+/// ```
+/// # use data_streams::{DataSource, Verified};
+/// use sha2::{Digest, Sha256};
+///
+/// let mut bytes = b"Hello, World!".to_vec();
+/// bytes.extend_from_slice(&Sha256::digest(&bytes));
+///
+/// let mut source = Verified::<_, Sha256>::new(&bytes[..]);
+/// let mut buf = [0; 64];
+/// assert_eq!(source.read_bytes(&mut buf)?, b"Hello, World!");
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// [`finish`]: Self::finish
+pub struct Verified<S, H: Digest> {
+	source: S,
+	hasher: H,
+	tail: Output<H>,
+	tail_len: usize,
+	checked: bool,
+}
+
+impl<S, H: Digest> Verified<S, H> {
+	/// Creates a verifying adapter over `source`.
+	pub fn new(source: S) -> Self {
+		Self {
+			source,
+			hasher: H::new(),
+			tail: Output::<H>::default(),
+			tail_len: 0,
+			checked: false,
+		}
+	}
+
+	/// Returns a reference to the inner source.
+	pub const fn get_ref(&self) -> &S { &self.source }
+
+	/// Returns a mutable reference to the inner source. Reading from it directly
+	/// will cause the verification to fail.
+	pub fn get_mut(&mut self) -> &mut S { &mut self.source }
+
+	/// Consumes the adapter without verifying, returning the inner source. Up to
+	/// the digest length in bytes read from it may be held back.
+	pub fn into_inner(self) -> S { self.source }
+
+	fn digest_len() -> usize { <H as Digest>::output_size() }
+
+	/// Compares the held-back bytes to the hash of the data read so far.
+	fn verify(&mut self) -> Result {
+		let len = Self::digest_len();
+		if self.tail_len < len {
+			return Err(Error::end(len, self.tail_len))
+		}
+
+		let hash = core::mem::replace(&mut self.hasher, H::new()).finalize();
+		self.checked = true;
+		if hash == self.tail {
+			Ok(())
+		} else {
+			Err(Error::ChecksumMismatch)
+		}
+	}
+}
+
+impl<S: DataSource, H: Digest> Verified<S, H> {
+	/// Reads the rest of the digest from the inner source, without reading past it,
+	/// and verifies it against the data read so far. Use this when the data isn't
+	/// followed by the end of the inner source.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::ChecksumMismatch`] if the digest doesn't match the data, or
+	/// [`Error::End`] if the inner source ends before the full digest is read.
+	pub fn finish(mut self) -> Result {
+		if self.checked {
+			return Ok(())
+		}
+
+		// The inner source may return fewer bytes than requested before its end.
+		let len = Self::digest_len();
+		while self.tail_len < len {
+			let count = self.source.read_bytes(&mut self.tail[self.tail_len..len])?.len();
+			if count == 0 {
+				break
+			}
+			self.tail_len += count;
+		}
+		self.verify()
+	}
+
+	/// Reads bytes from the inner source into the unfilled part of `buf`, passing
+	/// along bytes no longer needed as the digest. Returns the number of data bytes
+	/// written to the start of `buf`, and the number read from the inner source.
+	fn read_step(&mut self, buf: &mut [u8]) -> Result<(usize, usize)> {
+		let len = Self::digest_len();
+		let read_count = self.source.read_bytes(buf)?.len();
+		let held = self.tail_len;
+		let total = held + read_count;
+		let data_len = total.saturating_sub(len);
+
+		// The new tail is the last digest-length bytes of the held-back bytes
+		// followed by the read bytes.
+		let mut tail = Output::<H>::default();
+		let tail_len = total - data_len;
+		let from_held = tail_len.saturating_sub(read_count);
+		tail[..from_held].copy_from_slice(&self.tail[held - from_held..held]);
+		tail[from_held..tail_len].copy_from_slice(&buf[read_count - (tail_len - from_held)..read_count]);
+
+		// The data is the first bytes of the held-back bytes followed by the read
+		// bytes.
+		let data_held = data_len.min(held);
+		buf.copy_within(..data_len - data_held, data_held);
+		buf[..data_held].copy_from_slice(&self.tail[..data_held]);
+
+		self.hasher.update(&buf[..data_len]);
+		self.tail = tail;
+		self.tail_len = tail_len;
+		Ok((data_len, read_count))
+	}
+}
+
+impl<S: DataSource, H: Digest> DataSource for Verified<S, H> {
+	/// Returns the number of bytes available from the inner source, excluding
+	/// those which may be the digest.
+	fn available(&self) -> usize {
+		(self.tail_len + self.source.available()).saturating_sub(Self::digest_len())
+	}
+
+	fn request(&mut self, count: usize) -> Result<bool> {
+		let inner_count = (count + Self::digest_len()).saturating_sub(self.tail_len);
+		self.source.request(inner_count)?;
+		Ok(self.available() >= count)
+	}
+
+	fn skip(&mut self, count: usize) -> Result<usize> {
+		let mut buf = [0; 64];
+		let mut skipped = 0;
+		while skipped < count {
+			let len = buf.len().min(count - skipped);
+			let read = self.read_bytes(&mut buf[..len])?.len();
+			if read == 0 {
+				break
+			}
+			skipped += read;
+		}
+		Ok(skipped)
+	}
+
+	/// Reads bytes into a slice, returning the bytes read. When the inner source
+	/// ends, the digest is verified.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::ChecksumMismatch`] if the digest doesn't match the data,
+	/// or [`Error::End`] if the inner source ended before a full digest could be
+	/// read. Returns any IO errors encountered.
+	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		let mut filled = 0;
+		while filled < buf.len() {
+			let (data_len, read_count) = self.read_step(&mut buf[filled..])?;
+			filled += data_len;
+			if read_count == 0 {
+				if !self.checked {
+					self.verify()?;
+				}
+				break
+			}
+		}
+		Ok(&buf[..filled])
+	}
+}
@@ -4,17 +4,113 @@
 
 #[cfg(all(feature = "alloc", feature = "utf8"))]
 use alloc::string::String;
-use std::io::{BufRead, BufReader, BufWriter, Cursor, Empty, ErrorKind, Read, Repeat, Seek, Sink, Take, Write};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Chain, Cursor, Empty, ErrorKind, Read, Repeat, Seek, Sink, Take, Write};
+use std::path::Path;
 use crate::{
 	BufferAccess,
+	DatagramSink,
 	DataSink,
 	DataSource,
 	Error,
 	Result,
-	source::default_skip,
+	SliceSource,
+	source::{buf_read_u8, default_skip, read_bytes_greedy},
 };
 use crate::markers::source::{InfiniteSource, SourceSize};
 
+/// Opens a file for reading, buffered with a [`BufReader`]. The source's
+/// [`SourceSize`] upper bound is the byte count left in the file, read from its
+/// metadata, so reading the whole file can allocate once.
+///
+/// ```no_run
+/// # use data_streams::{fs, DataSource};
+/// let mut source = fs::open("data.bin")?;
+/// let magic = source.read_u32()?;
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns any IO errors encountered when opening the file.
+pub fn open(path: impl AsRef<Path>) -> Result<BufReader<File>> {
+	Ok(BufReader::new(File::open(path)?))
+}
+
+/// Creates or truncates a file for writing, buffered with a [`BufWriter`].
+///
+/// Dropping the sink flushes it, ignoring any errors. Call [`DataSink::flush`]
+/// when done writing, or use a [`flush_guard`], to handle them.
+///
+/// ```no_run
+/// # use data_streams::{fs, DataSink};
+/// let mut sink = fs::create("data.bin")?;
+/// sink.write_u32(0xCAFE_BABE)?;
+/// sink.flush()?;
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns any IO errors encountered when creating the file.
+///
+/// [`flush_guard`]: DataSink::flush_guard
+pub fn create(path: impl AsRef<Path>) -> Result<BufWriter<File>> {
+	Ok(BufWriter::new(File::create(path)?))
+}
+
+/// Calls `f` until it returns anything other than an [`ErrorKind::Interrupted`]
+/// error, converting the result. Signals may interrupt a blocking read or write
+/// before any bytes are transferred; the operation should then be retried. This
+/// lets [`DataSource`] implementations over a raw [`Read`] handle this like the
+/// built-in ones.
+///
+/// ```
+/// # use std::io::Read;
+/// # use data_streams::{retry_interrupted, DataSource, Result};
+/// struct Reader<R>(R);
+///
+/// impl<R: Read> DataSource for Reader<R> {
+///     fn available(&self) -> usize { 0 }
+///     // Without a buffer, there's no way to tell whether bytes are available
+///     // without reading them.
+///     fn request(&mut self, _: usize) -> Result<bool> { Ok(true) }
+///     fn skip(&mut self, count: usize) -> Result<usize> {
+///         let mut buf = [0; 64];
+///         let mut skipped = 0;
+///         while skipped < count {
+///             let len = buf.len().min(count - skipped);
+///             match self.read_bytes(&mut buf[..len])?.len() {
+///                 0 => break,
+///                 read => skipped += read
+///             }
+///         }
+///         Ok(skipped)
+///     }
+///     fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+///         let count = retry_interrupted(|| self.0.read(buf))?;
+///         Ok(&buf[..count])
+///     }
+/// }
+/// # let mut source = Reader(&b"abcdef"[..]);
+/// # assert_eq!(source.skip(2)?, 2);
+/// # assert_eq!(source.read_bytes(&mut [0; 3])?, b"cde");
+/// # assert_eq!(source.skip(100)?, 1);
+/// # Ok::<_, data_streams::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns the first error other than [`ErrorKind::Interrupted`] from `f`.
+pub fn retry_interrupted<T>(mut f: impl FnMut() -> std::io::Result<T>) -> Result<T> {
+	loop {
+		match f() {
+			Err(error) if error.kind() == ErrorKind::Interrupted => { }
+			result => return Ok(result?)
+		}
+	}
+}
+
 #[cfg(any(unix, windows, target_os = "wasi"))]
 unsafe impl SourceSize for &std::fs::File {
 	fn upper_bound(&self) -> Option<u64> {
@@ -29,7 +125,7 @@ unsafe impl SourceSize for &std::fs::File {
 			.ok()
 			.as_ref()
 			.map(size)
-			.map(|s| s - pos)
+			.map(|s| s.saturating_sub(pos))
 	}
 }
 
@@ -50,7 +146,15 @@ impl<R: Read + ?Sized> DataSource for BufReader<R> {
 	}
 
 	fn skip(&mut self, count: usize) -> Result<usize> {
-		Ok(buf_read_skip(self, count))
+		default_skip(self, count)
+	}
+
+	fn read_u8(&mut self) -> Result<u8> {
+		buf_read_u8(self)
+	}
+
+	fn read_i8(&mut self) -> Result<i8> {
+		self.read_u8().map(u8::cast_signed)
 	}
 
 	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
@@ -60,6 +164,20 @@ impl<R: Read + ?Sized> DataSource for BufReader<R> {
 	fn read_exact_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
 		buf_read_exact_bytes(self, buf)
 	}
+
+	// The buffered default validates each buffer fill separately, which reports a
+	// codepoint split across two fills as incomplete. Reading until `buf` is full
+	// or the stream ends before validating joins the split bytes instead.
+	#[cfg(feature = "utf8")]
+	fn read_utf8<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a str> {
+		let bytes = buf_read_bytes(self, buf)?;
+		Ok(simdutf8::compat::from_utf8(bytes)?)
+	}
+
+	#[cfg(all(feature = "alloc", feature = "utf8", not(feature = "unstable_specialization")))]
+	fn read_line_lossy(&mut self, buf: &mut String) -> Result<usize> {
+		crate::source::buf_read_line_lossy(self, buf)
+	}
 }
 
 impl<R: Read + ?Sized> BufferAccess for BufReader<R> {
@@ -68,7 +186,8 @@ impl<R: Read + ?Sized> BufferAccess for BufReader<R> {
 	fn buffer(&self) -> &[u8] { self.buffer() }
 
 	fn fill_buffer(&mut self) -> Result<&[u8]> {
-		Ok(self.fill_buf()?)
+		retry_interrupted(|| self.fill_buf().map(drop))?;
+		Ok(BufReader::buffer(self))
 	}
 
 	fn drain_buffer(&mut self, count: usize) {
@@ -76,13 +195,15 @@ impl<R: Read + ?Sized> BufferAccess for BufReader<R> {
 	}
 }
 
+// The inner reader has already produced the buffered bytes, so they're added to
+// its bounds.
 unsafe impl<R: Read + SourceSize + ?Sized> SourceSize for BufReader<R> {
 	fn lower_bound(&self) -> u64 {
-		self.get_ref().lower_bound()
+		self.get_ref().lower_bound().saturating_add(self.buffer().len() as u64)
 	}
 
 	fn upper_bound(&self) -> Option<u64> {
-		self.get_ref().upper_bound()
+		self.get_ref().upper_bound()?.checked_add(self.buffer().len() as u64)
 	}
 }
 
@@ -91,12 +212,87 @@ impl<W: Write + ?Sized> DataSink for BufWriter<W> {
 		self.write_all(buf)?;
 		Ok(())
 	}
+
+	fn try_write_bytes(&mut self, buf: &[u8]) -> Result<usize> {
+		try_write_all(self, buf)
+	}
+
+	fn flush(&mut self) -> Result {
+		Write::flush(self)?;
+		Ok(())
+	}
 }
 
+// TCP streams are written directly, without a BufWriter. To read, wrap the stream
+// in a BufReader, which provides the buffer a source needs.
+macro_rules! impl_tcp_sink {
+    ($($ty:ty),+) => {
+		$(
+		impl DataSink for $ty {
+			/// Writes all bytes from `buf` to the stream, retrying interrupted writes.
+			///
+			/// In blocking mode, this blocks until all bytes are written. In
+			/// non-blocking mode, a full send buffer returns an [`Error::Io`] with
+			/// [`ErrorKind::WouldBlock`], after an unknown count of bytes may have
+			/// been written. A framed protocol can't recover from this, so the
+			/// stream should be closed. Use [`try_write_bytes`] on non-blocking
+			/// streams instead.
+			///
+			/// # Errors
+			///
+			/// Returns any IO errors encountered.
+			///
+			/// [`try_write_bytes`]: DataSink::try_write_bytes
+			fn write_bytes(&mut self, buf: &[u8]) -> Result {
+				self.write_all(buf)?;
+				Ok(())
+			}
+
+			fn try_write_bytes(&mut self, buf: &[u8]) -> Result<usize> {
+				try_write_all(self, buf)
+			}
+		}
+		)+
+	};
+}
+
+impl_tcp_sink! { std::net::TcpStream, &std::net::TcpStream }
+
+macro_rules! impl_udp_sink {
+    ($($ty:ty),+) => {
+		$(
+		impl DatagramSink for $ty {
+			/// Sends `buf` as one datagram to the connected address, with
+			/// [`UdpSocket::send`](std::net::UdpSocket::send).
+			fn write_message(&mut self, buf: &[u8]) -> Result {
+				let sent = loop {
+					match self.send(buf) {
+						Err(error) if error.kind() == ErrorKind::Interrupted => { }
+						result => break result?
+					}
+				};
+
+				if sent < buf.len() {
+					Err(Error::overflow(buf.len() - sent, sent))
+				} else {
+					Ok(())
+				}
+			}
+		}
+		)+
+	};
+}
+
+impl_udp_sink! { std::net::UdpSocket, &std::net::UdpSocket }
+
 impl<T: AsRef<[u8]>> DataSource for Cursor<T> {
 	#[cfg(not(feature = "unstable_specialization"))]
 	fn available(&self) -> usize { self.buffer_count() }
 
+	fn remaining(&self) -> Option<u64> {
+		Some((cursor_as_slice(self).len() as u64).saturating_sub(self.position()))
+	}
+
 	fn request(&mut self, count: usize) -> Result<bool> {
 		Ok(self.available() >= count)
 	}
@@ -107,28 +303,49 @@ impl<T: AsRef<[u8]>> DataSource for Cursor<T> {
 		Ok(count)
 	}
 
+	fn read_u8(&mut self) -> Result<u8> {
+		buf_read_u8(self)
+	}
+
+	fn read_i8(&mut self) -> Result<i8> {
+		self.read_u8().map(u8::cast_signed)
+	}
+
 	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
-		let count = self.read(buf)?;
+		let count = retry_interrupted(|| self.read(buf))?;
 		Ok(&buf[..count])
 	}
 
 	fn read_exact_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
-		buf_read_exact_bytes(self, buf)
+		// The whole stream is in memory, so copy in one shot rather than looping
+		// through Read.
+		let slice = BufferAccess::buffer(self);
+		let count = buf.len().min(slice.len());
+		buf[..count].copy_from_slice(&slice[..count]);
+		self.consume(count);
+		if count == buf.len() {
+			Ok(buf)
+		} else {
+			Err(Error::end(buf.len(), count))
+		}
+	}
+
+	#[cfg(all(feature = "alloc", feature = "utf8", not(feature = "unstable_specialization")))]
+	fn read_line_lossy(&mut self, buf: &mut String) -> Result<usize> {
+		crate::source::buf_read_line_lossy(self, buf)
 	}
 }
 
 impl<T: AsRef<[u8]>> BufferAccess for Cursor<T> {
 	fn buffer_capacity(&self) -> usize { cursor_as_slice(self).len() }
 
-	fn buffer_count(&self) -> usize {
-		self.buffer_capacity()
-			.min(self.position() as usize)
-	}
+	fn buffer_count(&self) -> usize { self.buffer().len() }
 
 	fn buffer(&self) -> &[u8] {
-		// See Cursor::fill_buf and Cursor::split
+		// See Cursor::fill_buf and Cursor::split. The position may be past the end
+		// after seeking, in which case nothing is buffered.
 		let slice = cursor_as_slice(self);
-		let start = self.buffer_count();
+		let start = usize::try_from(self.position()).map_or(slice.len(), |pos| pos.min(slice.len()));
 		&slice[start..]
 	}
 
@@ -141,17 +358,30 @@ impl<T: AsRef<[u8]>> BufferAccess for Cursor<T> {
 	}
 }
 
+impl<'a> SliceSource<'a> for Cursor<&'a [u8]> {
+	fn read_slice_exact(&mut self, count: usize) -> Result<&'a [u8]> {
+		let slice: &'a [u8] = self.get_ref();
+		let start = usize::try_from(self.position()).map_or(slice.len(), |pos| pos.min(slice.len()));
+		let bytes = (&slice[start..]).read_slice_exact(count)?;
+		self.consume(count);
+		Ok(bytes)
+	}
+}
+
 unsafe impl<T: AsRef<[u8]>> SourceSize for Cursor<T> {
 	fn lower_bound(&self) -> u64 { self.buffer_count() as u64 }
 	fn upper_bound(&self) -> Option<u64> { Some(self.buffer_count() as u64) }
 }
 
+// Covers borrowed backing stores as well, such as Cursor<&mut [u8]>, which writes
+// in place and overflows at the end of the slice, and Cursor<&mut Vec<u8>>, which
+// grows. Writes start at the cursor position, preserving any bytes before it.
 impl<T> DataSink for Cursor<T> where Self: Write {
 	fn write_bytes(&mut self, buf: &[u8]) -> Result {
 		let count = self.write(buf)?;
 		if count < buf.len() {
 			let remaining = buf.len() - count;
-			Err(Error::Overflow { remaining })
+			Err(Error::overflow(remaining, count))
 		} else {
 			Ok(())
 		}
@@ -166,13 +396,20 @@ impl<T: BufferAccess + BufRead> DataSource for Take<T> {
 	#[cfg(not(feature = "unstable_specialization"))]
 	fn available(&self) -> usize { self.buffer_count() }
 
+	/// Returns the remaining byte count of the inner source, up to the limit.
+	fn remaining(&self) -> Option<u64> {
+		self.get_ref()
+			.remaining()
+			.map(|remaining| remaining.min(self.limit()))
+	}
+
 	#[cfg(not(feature = "unstable_specialization"))]
 	fn request(&mut self, count: usize) -> Result<bool> {
 		crate::source::default_request(self, count)
 	}
 
 	fn skip(&mut self, count: usize) -> Result<usize> {
-		Ok(buf_read_skip(self, count))
+		default_skip(self, count)
 	}
 
 	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
@@ -200,7 +437,8 @@ impl<T: BufferAccess + BufRead> BufferAccess for Take<T> {
 	}
 
 	fn fill_buffer(&mut self) -> Result<&[u8]> {
-		Ok(self.fill_buf()?)
+		retry_interrupted(|| self.fill_buf().map(drop))?;
+		Ok(BufferAccess::buffer(self))
 	}
 
 	fn drain_buffer(&mut self, count: usize) {
@@ -214,6 +452,97 @@ unsafe impl<T> SourceSize for Take<T> {
 	}
 }
 
+// BufferAccess isn't implemented, because Chain doesn't expose which half it's
+// reading from. A buffer view of the second half could be returned while the first
+// half still has unbuffered data, reading bytes out of order.
+impl<A: DataSource, B: DataSource> DataSource for Chain<A, B> {
+	/// Returns the number of bytes available for reading from the first source, or
+	/// from the second if the first has none buffered.
+	fn available(&self) -> usize {
+		let (first, second) = self.get_ref();
+		match first.available() {
+			0 => second.available(),
+			count => count
+		}
+	}
+
+	/// Returns the sum of the remaining byte counts of both sources, if both are
+	/// known.
+	fn remaining(&self) -> Option<u64> {
+		let (first, second) = self.get_ref();
+		first.remaining()?.checked_add(second.remaining()?)
+	}
+
+	/// Requests `count` bytes from the first source, or from the second if the first
+	/// has none buffered after the request. Bytes can't be buffered across both
+	/// sources, so this returns `false` if the first source ends before `count`
+	/// bytes. Requests don't consume bytes, so reads still take the first source's
+	/// bytes before the second's.
+	fn request(&mut self, count: usize) -> Result<bool> {
+		let (first, second) = self.get_mut();
+		if first.request(count)? {
+			Ok(true)
+		} else if first.available() == 0 {
+			second.request(count)
+		} else {
+			Ok(false)
+		}
+	}
+
+	/// Skips bytes from the first source until it skips none, then from the second.
+	fn skip(&mut self, count: usize) -> Result<usize> {
+		let (first, second) = self.get_mut();
+		let mut skip_count = 0;
+		while skip_count < count {
+			match first.skip(count - skip_count)? {
+				0 => break,
+				skipped => skip_count += skipped
+			}
+		}
+		if skip_count < count {
+			skip_count += second.skip(count - skip_count)?;
+		}
+		Ok(skip_count)
+	}
+
+	/// Reads bytes from the first source, or from the second once the first reads
+	/// none. Like [`io::Chain`], a short read from the first source doesn't mean it
+	/// has ended, so its bytes aren't followed by the second's in the same read.
+	///
+	/// [`io::Chain`]: Chain
+	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		let (first, second) = self.get_mut();
+		let count = first.read_bytes(buf)?.len();
+		if count > 0 || buf.is_empty() {
+			Ok(&buf[..count])
+		} else {
+			second.read_bytes(buf)
+		}
+	}
+
+	fn read_exact_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		let len = buf.len();
+		let count = read_bytes_greedy(self, buf)?.len();
+		if count < len {
+			Err(Error::end(len, count))
+		} else {
+			Ok(buf)
+		}
+	}
+}
+
+unsafe impl<A: SourceSize, B: SourceSize> SourceSize for Chain<A, B> {
+	fn lower_bound(&self) -> u64 {
+		let (first, second) = self.get_ref();
+		first.lower_bound().saturating_add(second.lower_bound())
+	}
+
+	fn upper_bound(&self) -> Option<u64> {
+		let (first, second) = self.get_ref();
+		first.upper_bound()?.checked_add(second.upper_bound()?)
+	}
+}
+
 macro_rules! fixed_stream_impl {
     (impl $trait:ident for $stream:ident {
 		$($item:item)+
@@ -297,9 +626,13 @@ impl DataSource for Repeat {
 				core::str::from_utf8_unchecked(bytes)
 			}),
 			bytes =>
-				// Use from_utf8 to convert the byte into a UTF-8 error.
-				// Unwrap is safe because non-ASCII bytes are not valid UTF-8.
-				Err(simdutf8::compat::from_utf8(&bytes[..1]).unwrap_err().into())
+				// A repeated non-ASCII byte is never valid UTF-8: a continuation
+				// byte can't start a char, and a lead byte is followed by itself
+				// rather than a continuation byte. Validating the first two bytes
+				// gives the same error as the whole slice, which is only reported
+				// as incomplete if the slice is one byte long. Unwrap is safe for
+				// the same reason.
+				Err(simdutf8::compat::from_utf8(&bytes[..bytes.len().min(2)]).unwrap_err().into())
 		}
 	}
 
@@ -318,40 +651,46 @@ impl DataSource for Repeat {
 
 unsafe impl InfiniteSource for Repeat { }
 
-fn buf_read_skip(source: &mut (impl BufferAccess + ?Sized), count: usize) -> usize {
-	let mut skip_count = 0;
-	while skip_count < count {
-		let cur_skip_count = default_skip(&mut *source, count);
-		skip_count += cur_skip_count;
-
-		if cur_skip_count == 0 {
-			break
+/// Writes bytes until `buf` is written or the writer would block, retrying
+/// interrupted writes. Returns the number of bytes written.
+fn try_write_all(writer: &mut (impl Write + ?Sized), buf: &[u8]) -> Result<usize> {
+	let mut count = 0;
+	while count < buf.len() {
+		match writer.write(&buf[count..]) {
+			Ok(0) => return Err(std::io::Error::from(ErrorKind::WriteZero).into()),
+			Ok(cur_count) => count += cur_count,
+			Err(err) if err.kind() == ErrorKind::Interrupted => { }
+			Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+			Err(err) => return Err(err.into())
 		}
 	}
-	skip_count
+	Ok(count)
 }
 
 fn buf_read_bytes<'a>(source: &mut (impl Read + ?Sized), buf: &'a mut [u8]) -> Result<&'a [u8]> {
-	use ErrorKind::Interrupted;
-
+	// Reading into an empty slice may still fill the buffer from the stream, so
+	// stop once the slice is full.
 	let mut count = 0;
-	loop {
-		match source.read(buf) {
-			Ok(0) => break Ok(&buf[..count]),
-			Ok(cur_count) => count += cur_count,
-			Err(err) if err.kind() == Interrupted => { }
-			Err(err) => break Err(err.into())
+	while count < buf.len() {
+		match retry_interrupted(|| source.read(&mut buf[count..]))? {
+			0 => break,
+			cur_count => count += cur_count
 		}
 	}
+	Ok(&buf[..count])
 }
 
+// Read::read_exact doesn't report how many bytes were read before the end, so we
+// loop here instead.
 fn buf_read_exact_bytes<'a>(source: &mut (impl Read + ?Sized), buf: &'a mut [u8]) -> Result<&'a [u8]> {
-	match source.read_exact(&mut *buf) {
-		Ok(()) => Ok(buf),
-		Err(error) if error.kind() == ErrorKind::UnexpectedEof =>
-			Err(Error::End { required_count: buf.len() }),
-		Err(error) => Err(error.into())
+	let mut count = 0;
+	while count < buf.len() {
+		match retry_interrupted(|| source.read(&mut buf[count..]))? {
+			0 => return Err(Error::end(buf.len(), count)),
+			cur_count => count += cur_count
+		}
 	}
+	Ok(buf)
 }
 
 #[cfg(all(feature = "alloc", feature = "utf8"))]
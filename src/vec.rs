@@ -8,30 +8,63 @@ use alloc::{collections::VecDeque, vec::Vec};
 use core::mem::MaybeUninit;
 #[cfg(feature = "utf8")]
 use simdutf8::compat::from_utf8;
-#[cfg(any(feature = "utf8", feature = "unstable_ascii_char"))]
+#[cfg(any(feature = "utf8", feature = "unstable_ascii_char", feature = "unstable_specialization"))]
 use crate::Error;
 use crate::{BufferAccess, DataSink, DataSource, Result};
 use crate::markers::source::SourceSize;
-use crate::source::{max_multiple_of, VecSource};
+use crate::source::{max_multiple_of, ExtendFromSource, VecSource};
 #[cfg(feature = "utf8")]
 use crate::utf8::utf8_char_width;
 
+// Appends integers directly to the vector, skipping the generic write_data path.
+// These and write_bytes are inlined so that serializing many values approaches the
+// throughput of extend_from_slice, rather than making several calls per value.
+macro_rules! write_int {
+    ($($name:ident($ty:ty) => $to_bytes:ident;)+) => {
+		$(
+		#[inline]
+		fn $name(&mut self, value: $ty) -> Result {
+			self.write_bytes(&value.$to_bytes())
+		}
+		)+
+	};
+}
+
 impl DataSink for Vec<u8> {
+	#[inline]
 	fn write_bytes(&mut self, buf: &[u8]) -> Result {
 		self.try_reserve(buf.len())?;
 		self.extend_from_slice(buf);
 		Ok(())
 	}
 
-	fn write_utf8_codepoint(&mut self, value: char) -> Result {
+	fn reserve(&mut self, additional: usize) -> Result {
+		self.try_reserve(additional)?;
+		Ok(())
+	}
+
+	fn reserve_exact(&mut self, additional: usize) -> Result {
+		self.try_reserve_exact(additional)?;
+		Ok(())
+	}
+
+	fn write_iter(&mut self, iter: impl IntoIterator<Item = u8>) -> Result {
+		let iter = iter.into_iter();
+		self.try_reserve(iter.size_hint().0)?;
+		self.extend(iter);
+		Ok(())
+	}
+
+	fn write_char(&mut self, value: char) -> Result<usize> {
 		let start = self.len();
 		let width = value.len_utf8();
 		self.try_reserve(width)?;
 		self.resize(start + width, 0);
 		value.encode_utf8(&mut self[start..]);
-		Ok(())
+		Ok(width)
 	}
 
+	#[inline]
 	fn write_u8(&mut self, value: u8) -> Result {
 		self.try_reserve(1)?;
 		self.push(value);
@@ -41,10 +74,68 @@ impl DataSink for Vec<u8> {
 	fn write_i8(&mut self, value: i8) -> Result {
 		self.write_u8(value as u8)
 	}
+
+	write_int! {
+		write_u16(u16) => to_be_bytes;
+		write_i16(i16) => to_be_bytes;
+		write_u16_le(u16) => to_le_bytes;
+		write_i16_le(i16) => to_le_bytes;
+		write_u32(u32) => to_be_bytes;
+		write_i32(i32) => to_be_bytes;
+		write_u32_le(u32) => to_le_bytes;
+		write_i32_le(i32) => to_le_bytes;
+		write_u64(u64) => to_be_bytes;
+		write_i64(i64) => to_be_bytes;
+		write_u64_le(u64) => to_le_bytes;
+		write_i64_le(i64) => to_le_bytes;
+		write_u128(u128) => to_be_bytes;
+		write_i128(i128) => to_be_bytes;
+		write_u128_le(u128) => to_le_bytes;
+		write_i128_le(i128) => to_le_bytes;
+	}
+}
+
+impl ExtendFromSource for Vec<u8> {
+	fn extend_from_source(&mut self, source: &mut (impl DataSource + ?Sized)) -> Result<&[u8]> {
+		const CHUNK_SIZE: usize = 8 * 1024;
+
+		#[cfg(feature = "unstable_specialization")]
+		if crate::source::is_infinite(source) {
+			return Err(Error::NoEnd)
+		}
+
+		let start = self.len();
+		if let Some(remaining) = source.remaining() {
+			self.try_reserve(remaining.try_into().unwrap_or(usize::MAX))?;
+		}
+
+		loop {
+			if self.len() == self.capacity() {
+				self.try_reserve(CHUNK_SIZE)?;
+			}
+
+			// Only a chunk of the spare capacity is zeroed for each read, rather than
+			// all of it.
+			let len = self.len();
+			self.resize(len + (self.capacity() - len).min(CHUNK_SIZE), 0);
+			let count = match source.read_bytes(&mut self[len..]) {
+				Ok(bytes) => bytes.len(),
+				Err(error) => {
+					self.truncate(len);
+					break Err(error)
+				}
+			};
+			self.truncate(len + count);
+			if count == 0 {
+				break Ok(&self[start..])
+			}
+		}
+	}
 }
 
 impl DataSource for VecDeque<u8> {
 	fn available(&self) -> usize { self.len() }
+	fn remaining(&self) -> Option<u64> { Some(self.len() as u64) }
 
 	fn request(&mut self, count: usize) -> Result<bool> {
 		Ok(self.len() >= count)
@@ -57,11 +148,12 @@ impl DataSource for VecDeque<u8> {
 	}
 
 	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
-		let (mut a, mut b) = self.as_slices();
-		let mut slice = &mut *buf;
-		let mut count = a.read_bytes(slice)?.len();
-		slice = &mut slice[count..];
-		count += b.read_bytes(slice)?.len();
+		let (a, b) = self.as_slices();
+		let a_count = a.len().min(buf.len());
+		let b_count = b.len().min(buf.len() - a_count);
+		buf[..a_count].copy_from_slice(&a[..a_count]);
+		buf[a_count..][..b_count].copy_from_slice(&b[..b_count]);
+		let count = a_count + b_count;
 		self.drain_buffer(count);
 		Ok(&buf[..count])
 	}
@@ -194,17 +286,30 @@ impl DataSource for VecDeque<u8> {
 			Err(Error::invalid_ascii(buf[a_count], a_count, a_count))
 		}
 	}
+
+	#[cfg(all(feature = "utf8", not(feature = "unstable_specialization")))]
+	fn read_line_lossy(&mut self, buf: &mut alloc::string::String) -> Result<usize> {
+		crate::source::buf_read_line_lossy(self, buf)
+	}
 }
 
 impl BufferAccess for VecDeque<u8> {
 	fn buffer_capacity(&self) -> usize { self.capacity() }
 
+	// The buffer slice is only the front of a wrapped deque, but all its bytes
+	// are buffered.
+	fn buffer_count(&self) -> usize { self.len() }
+
 	fn buffer(&self) -> &[u8] { self.as_slices().0 }
 
 	fn fill_buffer(&mut self) -> Result<&[u8]> {
 		Ok((*self).buffer()) // Nothing to read
 	}
 
+	fn buffer_contiguous(&mut self) -> &[u8] {
+		self.make_contiguous()
+	}
+
 	fn clear_buffer(&mut self) {
 		self.clear();
 	}
@@ -268,6 +373,23 @@ impl DataSink for VecDeque<u8> {
 		Ok(())
 	}
 
+	fn reserve(&mut self, additional: usize) -> Result {
+		self.try_reserve(additional)?;
+		Ok(())
+	}
+
+	fn reserve_exact(&mut self, additional: usize) -> Result {
+		self.try_reserve_exact(additional)?;
+		Ok(())
+	}
+
+	fn write_iter(&mut self, iter: impl IntoIterator<Item = u8>) -> Result {
+		let iter = iter.into_iter();
+		self.try_reserve(iter.size_hint().0)?;
+		self.extend(iter);
+		Ok(())
+	}
+
 	fn write_u8(&mut self, value: u8) -> Result {
 		self.try_reserve(1)?;
 		self.push_back(value);
@@ -289,7 +411,8 @@ impl DataSink for alloc::string::String {
 	/// valid UTF-8 is written. [`Utf8Error::valid_up_to`] in this error returns
 	/// the number of valid bytes written to the string.
 	///
-	/// [`Error::Allocation`] is returned when capacity cannot be allocated.
+	/// [`Error::Allocation`] is returned when capacity cannot be allocated. Capacity
+	/// is reserved before writing, so the string is left unchanged in this case.
 	fn write_bytes(&mut self, buf: &[u8]) -> Result {
 		let (valid, result) = match from_utf8(buf).map_err(crate::Utf8Error::from) {
 			Ok(str) => (str, Ok(())),
@@ -301,24 +424,98 @@ impl DataSink for alloc::string::String {
 		self.write_utf8(valid)?;
 		result
 	}
-	/// Writes a UTF-8 string.
+	/// Writes all valid UTF-8 bytes from `iter`. The bytes are collected before
+	/// being validated, so codepoints aren't split.
 	///
 	/// # Errors
 	///
+	/// Returns [`Error::Utf8`] if the bytes contain invalid UTF-8. In this case,
+	/// any valid UTF-8 is written. [`Utf8Error::valid_up_to`] in this error returns
+	/// the number of valid bytes written to the string.
+	///
 	/// [`Error::Allocation`] is returned when capacity cannot be allocated.
+	fn write_iter(&mut self, iter: impl IntoIterator<Item = u8>) -> Result {
+		let mut bytes = Vec::new();
+		bytes.write_iter(iter)?;
+		self.write_bytes(&bytes)
+	}
+	/// Reserves capacity for at least `additional` more bytes.
+	///
+	/// # Errors
+	///
+	/// [`Error::Allocation`] is returned when capacity cannot be allocated.
+	fn reserve(&mut self, additional: usize) -> Result {
+		self.try_reserve(additional)?;
+		Ok(())
+	}
+	/// Reserves capacity for exactly `additional` more bytes.
+	///
+	/// # Errors
+	///
+	/// [`Error::Allocation`] is returned when capacity cannot be allocated.
+	fn reserve_exact(&mut self, additional: usize) -> Result {
+		self.try_reserve_exact(additional)?;
+		Ok(())
+	}
+	/// Writes a UTF-8 string.
+	///
+	/// # Errors
+	///
+	/// [`Error::Allocation`] is returned when capacity cannot be allocated, leaving
+	/// the string unchanged.
 	fn write_utf8(&mut self, value: &str) -> Result {
 		self.try_reserve(value.len())?;
 		self.push_str(value);
 		Ok(())
 	}
-	/// Writes a single UTF-8 codepoint.
+	/// Writes a single UTF-8 codepoint, returning its encoded width in bytes.
 	/// 
 	/// # Errors
 	/// 
 	/// [`Error::Allocation`] is returned when capacity cannot be allocated.
-	fn write_utf8_codepoint(&mut self, value: char) -> Result {
-		self.try_reserve(value.len_utf8())?;
+	fn write_char(&mut self, value: char) -> Result<usize> {
+		let width = value.len_utf8();
+		self.try_reserve(width)?;
 		self.push(value);
-		Ok(())
+		Ok(width)
+	}
+}
+
+// An empty sink can take ownership of the written buffer instead of copying from
+// it, as long as this doesn't discard a larger allocation of its own.
+#[cfg(feature = "unstable_specialization")]
+impl crate::VecSink for Vec<u8> {
+	fn write_owned_bytes(&mut self, buf: Vec<u8>) -> Result {
+		if self.is_empty() && self.capacity() <= buf.capacity() {
+			*self = buf;
+			Ok(())
+		} else {
+			self.write_bytes(&buf)
+		}
+	}
+}
+
+#[cfg(feature = "unstable_specialization")]
+impl crate::VecSink for VecDeque<u8> {
+	fn write_owned_bytes(&mut self, buf: Vec<u8>) -> Result {
+		if self.is_empty() && self.capacity() <= buf.capacity() {
+			// Converting a vector to a deque reuses its allocation.
+			*self = buf.into();
+			Ok(())
+		} else {
+			self.write_bytes(&buf)
+		}
+	}
+}
+
+#[cfg(all(feature = "unstable_specialization", feature = "utf8"))]
+impl crate::VecSink for alloc::string::String {
+	fn write_owned_utf8(&mut self, buf: alloc::string::String) -> Result {
+		if self.is_empty() && self.capacity() <= buf.capacity() {
+			*self = buf;
+			Ok(())
+		} else {
+			self.write_utf8(&buf)
+		}
 	}
 }
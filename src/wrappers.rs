@@ -6,6 +6,8 @@ use alloc::boxed::Box;
 use crate::{BufferAccess, DataSink, Result};
 #[cfg(not(feature = "unstable_specialization"))]
 use crate::{DataSource, source::default_read_array};
+#[cfg(all(feature = "alloc", not(feature = "unstable_specialization")))]
+use crate::VecSource;
 
 // Todo: DataSource couldn't be implemented for &mut <source> when specialization
 //  is enabled.
@@ -33,8 +35,12 @@ macro_rules! impl_buf_access {
 			delegate_impl! {
 				with **self;
 				fn buffer_capacity(&self) -> usize;
+				fn buffer_count(&self) -> usize;
 				fn buffer(&self) -> &[u8];
 				fn fill_buffer(&mut self) -> Result<&[u8]>;
+				fn fill_exact(&mut self, count: usize) -> Result<&[u8]>;
+				fn grow_buffer(&mut self, min_capacity: usize) -> Result;
+				fn buffer_contiguous(&mut self) -> &[u8];
 				fn clear_buffer(&mut self);
 				fn drain_buffer(&mut self, count: usize);
 			}
@@ -56,6 +62,7 @@ macro_rules! impl_source {
 			delegate_impl! {
 				with **self;
 				fn available(&self) -> usize;
+				fn remaining(&self) -> Option<u64>;
 				fn request(&mut self, count: usize) -> Result<bool>;
 				fn skip(&mut self, count: usize) -> Result<usize>;
 				fn require(&mut self, count: usize) -> Result;
@@ -81,6 +88,9 @@ macro_rules! impl_source {
 				fn read_isize(&mut self) -> Result<isize>;
 				fn read_usize_le(&mut self) -> Result<usize>;
 				fn read_isize_le(&mut self) -> Result<isize>;
+				fn read_usize_sized(&mut self, bytes: usize) -> Result<usize>;
+				fn read_usize_sized_le(&mut self, bytes: usize) -> Result<usize>;
+				fn read_fixed_i16_q8(&mut self) -> Result<f32>;
 			}
 
 			fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
@@ -95,10 +105,28 @@ macro_rules! impl_source {
 				default_read_array(&mut **self)
 			}
 
+			#[cfg(feature = "unstable_borrowed_buf")]
+			fn read_into_borrowed(&mut self, cursor: &mut core::io::BorrowedCursor<'_>) -> Result<usize> {
+				(**self).read_into_borrowed(cursor)
+			}
+
+			fn read_into_uninit<'a>(&mut self, buf: &'a mut [core::mem::MaybeUninit<u8>]) -> Result<&'a [u8]> {
+				(**self).read_into_uninit(buf)
+			}
+
 			#[cfg(feature = "utf8")]
 			fn read_utf8<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a str> {
 				(**self).read_utf8(buf)
 			}
+
+			fn read_ascii_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+				(**self).read_ascii_bytes(buf)
+			}
+
+			#[cfg(all(feature = "alloc", feature = "utf8"))]
+			fn read_line_lossy(&mut self, buf: &mut alloc::string::String) -> Result<usize> {
+				(**self).read_line_lossy(buf)
+			}
 		})+
 	};
 }
@@ -114,6 +142,31 @@ impl_source! {
 	impl<S> for Box<S>;
 }
 
+// With specialization, the blanket impl over buffered sources covers these.
+macro_rules! impl_vec_source {
+    ($($(#[$attr:meta])? impl<$gen:ident> for $ty:ty;)+) => {
+		$(
+		$(#[$attr])?
+		impl<$gen: VecSource + ?Sized> VecSource for $ty {
+			fn read_to_end<'a>(&mut self, buf: &'a mut alloc::vec::Vec<u8>) -> Result<&'a [u8]> {
+				(**self).read_to_end(buf)
+			}
+
+			#[cfg(feature = "utf8")]
+			fn read_utf8_to_end<'a>(&mut self, buf: &'a mut alloc::string::String) -> Result<&'a str> {
+				(**self).read_utf8_to_end(buf)
+			}
+		})+
+	};
+}
+
+impl_vec_source! {
+	#[cfg(all(feature = "alloc", not(feature = "unstable_specialization")))]
+	impl<S> for &mut S;
+	#[cfg(all(feature = "alloc", not(feature = "unstable_specialization")))]
+	impl<S> for Box<S>;
+}
+
 macro_rules! impl_sink {
     ($($(#[$attr:meta])? impl<$gen:ident> for $ty:ty;)+) => {
 		$(
@@ -122,7 +175,12 @@ macro_rules! impl_sink {
 			delegate_impl! {
 				with **self;
 				fn write_bytes(&mut self, buf: &[u8]) -> Result;
+				fn try_write_bytes(&mut self, buf: &[u8]) -> Result<usize>;
+				fn reserve(&mut self, additional: usize) -> Result;
+				fn reserve_exact(&mut self, additional: usize) -> Result;
+				fn flush(&mut self) -> Result;
 				fn write_utf8(&mut self, value: &str) -> Result;
+				fn write_char(&mut self, value: char) -> Result<usize>;
 				fn write_u8(&mut self, value: u8) -> Result;
 				fn write_i8(&mut self, value: i8) -> Result;
 				fn write_u16(&mut self, value: u16) -> Result;
@@ -145,6 +203,14 @@ macro_rules! impl_sink {
 				fn write_isize(&mut self, value: isize) -> Result;
 				fn write_usize_le(&mut self, value: usize) -> Result;
 				fn write_isize_le(&mut self, value: isize) -> Result;
+				fn write_usize_sized(&mut self, value: usize, bytes: usize) -> Result;
+				fn write_usize_sized_le(&mut self, value: usize, bytes: usize) -> Result;
+				fn write_fixed_i16_q8(&mut self, value: f32) -> Result;
+			}
+
+			#[cfg(feature = "utf8")]
+			fn write_utf8_lossy(&mut self, bytes: &[u8]) -> Result {
+				(**self).write_utf8_lossy(bytes)
 			}
 		}
 		)+
@@ -0,0 +1,272 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use data_streams::{ArraySource, BufferAccess, DataSource, Error, GenericDataSource, Result, SliceSource, StrSource};
+use data_streams::markers::source::SourceSize;
+
+#[test]
+fn read_header() -> Result {
+	let mut source = ArraySource::new(*b"RIFF\x24\0\0\0WAVE");
+	assert_eq!(source.read_array::<4>()?, *b"RIFF");
+	assert_eq!(source.read_u32_le()?, 0x24);
+	assert_eq!(source.lower_bound(), 4);
+	assert_eq!(source.upper_bound(), Some(4));
+	assert_eq!(source.buffer(), b"WAVE");
+	assert_eq!(source.read_exact_bytes(&mut [0; 5]), Err(Error::end(5, 0)));
+	assert_eq!(source.read_bytes(&mut [0; 5])?, b"WAVE");
+	assert_eq!(source.offset(), 12);
+	assert_eq!(source.remaining(), Some(0));
+	Ok(())
+}
+
+#[test]
+fn skip() -> Result {
+	let mut source = ArraySource::from([1, 2, 3]);
+	assert_eq!(source.skip(2)?, 2);
+	assert_eq!(source.skip(2)?, 1);
+	assert_eq!(source.into_inner(), [1, 2, 3]);
+	Ok(())
+}
+
+#[test]
+fn read_data_large() -> Result {
+	use data_streams::IterSource;
+
+	let mut source = IterSource::new((0..=255).cycle().take(1500));
+	let value: [u8; 1024] = source.read_data()?;
+	assert!(value.iter().copied().eq((0..=255).cycle().take(1024)));
+
+	// The source ends without an upper bound to rule the read out beforehand.
+	let mut source = IterSource::new(core::iter::successors(Some(0), |&i| (i < 99).then_some(i + 1)));
+	assert_eq!(GenericDataSource::<[u8; 1024]>::read_data(&mut source), Err(Error::end(1024, 100)));
+	Ok(())
+}
+
+#[test]
+fn read_data_array() -> Result {
+	let mut source = ArraySource::new([0, 1, 0, 2, 0, 3, 0, 4, 0]);
+	let records: [u16; 2] = source.read_data_array()?;
+	assert_eq!(records, [1, 2].map(u16::to_be));
+	assert_eq!(source.try_read_data_array::<2>()?, Some([3, 4].map(u16::to_be)));
+	assert_eq!(GenericDataSource::<u16>::try_read_data_array::<2>(&mut source), Err(Error::end(4, 0)));
+	assert_eq!(source.skip(1)?, 1);
+	assert_eq!(GenericDataSource::<u16>::try_read_data_array::<2>(&mut source), Ok(None));
+	Ok(())
+}
+
+#[test]
+fn str_source() -> Result {
+	let mut source = StrSource::new("\u{2}é!");
+	assert_eq!(source.read_u8()?, 2);
+	assert_eq!(source.read_u8()?, 0xC3);
+	assert_eq!(source.as_str(), None);
+	assert_eq!(source.remaining(), Some(2));
+	assert_eq!(source.read_bytes(&mut [0; 4])?, [0xA9, b'!']);
+	assert_eq!(source.as_str(), Some(""));
+	Ok(())
+}
+
+// With specialization, VecSource is implemented for buffered sources by a blanket
+// impl, which this can't override.
+#[cfg(not(feature = "unstable_specialization"))]
+#[test]
+fn read_to_end_checked() -> Result {
+	use data_streams::VecSource;
+
+	// Claims at least 8 bytes, but only has 4.
+	struct Lying(ArraySource<4>);
+
+	impl DataSource for Lying {
+		fn available(&self) -> usize { self.0.available() }
+		fn request(&mut self, count: usize) -> Result<bool> { self.0.request(count) }
+		fn skip(&mut self, count: usize) -> Result<usize> { self.0.skip(count) }
+		fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> { self.0.read_bytes(buf) }
+	}
+
+	impl VecSource for Lying {
+		fn read_to_end<'a>(&mut self, buf: &'a mut Vec<u8>) -> Result<&'a [u8]> {
+			let start = buf.len();
+			self.read_into_vec(buf, 4)?;
+			Ok(&buf[start..])
+		}
+
+		#[cfg(feature = "utf8")]
+		fn read_utf8_to_end<'a>(&mut self, buf: &'a mut String) -> Result<&'a str> {
+			let start = buf.len();
+			let bytes = self.read_to_end(&mut Vec::new())?.to_vec();
+			buf.push_str(&String::from_utf8(bytes).expect("invalid UTF-8"));
+			Ok(&buf[start..])
+		}
+	}
+
+	unsafe impl SourceSize for Lying {
+		fn lower_bound(&self) -> u64 { 8 }
+	}
+
+	let mut buf = Vec::new();
+	assert_eq!(Lying(ArraySource::new(*b"abcd")).read_to_end_checked(&mut buf, 2)?, b"ab");
+	assert_eq!(
+		Lying(ArraySource::new(*b"abcd")).read_to_end_checked(&mut buf, 16),
+		Err(Error::size_mismatch(4, 8, None))
+	);
+	assert_eq!(buf, b"ababcd");
+	Ok(())
+}
+
+#[test]
+fn read_data_slice_partial_end() -> Result {
+	let mut buf = [0; 4];
+	let mut source = &b"\x01\x02\x03\x04\x05"[..];
+	let values = GenericDataSource::<i16>::read_data_slice(&mut source, &mut buf)?;
+	assert_eq!(values, [i16::from_ne_bytes([1, 2]), i16::from_ne_bytes([3, 4])]);
+	assert_eq!(source, b"\x05");
+
+	let mut source = std::collections::VecDeque::from(*b"\x01\x02\x03");
+	let values = GenericDataSource::<i16>::read_data_slice(&mut source, &mut buf)?;
+	assert_eq!(values, [i16::from_ne_bytes([1, 2])]);
+	assert_eq!(source.read_u8()?, 3);
+	Ok(())
+}
+
+#[test]
+fn try_read_data_slice_bytes() -> Result {
+	#[repr(align(4))]
+	struct Aligned([u8; 12]);
+
+	let mut buf = Aligned([0; 12]);
+	let mut source = &[1u8; 10][..];
+	assert_eq!(
+		GenericDataSource::<u32>::try_read_data_slice(&mut source, &mut buf.0[2..]),
+		Err(Error::unaligned_buffer(4))
+	);
+	assert_eq!(source.len(), 10);
+	let values = GenericDataSource::<u32>::try_read_data_slice(&mut source, &mut buf.0[..10])?;
+	assert_eq!(values, [0x0101_0101; 2]);
+	assert_eq!(source, [1, 1]);
+	assert_eq!(buf.0[8..], [0; 4]);
+	Ok(())
+}
+
+#[test]
+fn read_data_slice_remainder() -> Result {
+	use std::io::Cursor;
+
+	let bytes = [0, 1, 0, 2, 3];
+	let expected = [u16::from_ne_bytes([0, 1]), u16::from_ne_bytes([0, 2])];
+	let mut buf = [0; 4];
+
+	let mut source = Cursor::new(bytes);
+	assert_eq!(GenericDataSource::<u16>::read_data_slice(&mut source, &mut buf)?, expected);
+	assert_eq!(source.position(), 4);
+	assert_eq!(source.read_bytes(&mut [0; 4])?, [3]);
+
+	let mut source = ArraySource::new(bytes);
+	assert_eq!(GenericDataSource::<u16>::read_data_slice(&mut source, &mut buf)?, expected);
+	assert_eq!(source.read_bytes(&mut [0; 4])?, [3]);
+	// Only the remainder is left, which isn't enough for another value.
+	assert_eq!(GenericDataSource::<u16>::read_data_slice(&mut source, &mut buf)?, []);
+	Ok(())
+}
+
+#[test]
+fn vec_request_at_capacity() -> Result {
+	let mut source = Vec::with_capacity(4);
+	source.extend_from_slice(b"abcd");
+	assert!(source.request(4)?);
+	assert!(!source.request(5)?);
+	assert_eq!(source.require(5), Err(Error::end(5, 0)));
+	assert_eq!(source.fill_exact(5), Err(Error::end(5, 4)));
+	assert_eq!(source.read_u16()?, 0x6162);
+	assert!(!source.request(3)?);
+	Ok(())
+}
+
+#[test]
+fn slice_request_at_capacity() -> Result {
+	let mut source = &b"abcd"[..];
+	assert_eq!(source.buffer_capacity(), 4);
+	assert!(source.request(4)?);
+	for count in [5, usize::MAX] {
+		assert!(!source.request(count)?, "count {count}");
+		assert_eq!(source.require(count), Err(Error::end(count, 0)), "count {count}");
+	}
+	assert_eq!(source.fill_exact(5), Err(Error::end(5, 4)));
+	assert_eq!(source, b"abcd");
+
+	let mut source = ArraySource::new(*b"abcd");
+	source.skip(1)?;
+	assert!(source.request(3)?);
+	assert!(!source.request(4)?);
+
+	let mut source = StrSource::new("abcd");
+	assert!(!source.request(usize::MAX)?);
+	assert_eq!(source.remaining(), Some(4));
+	Ok(())
+}
+
+#[test]
+fn read_magic_truncated() {
+	let mut source = &b"\x89PN"[..];
+	assert_eq!(source.read_magic(b"\x89PNG"), Err(Error::end(4, 0)));
+	assert_eq!(source, b"\x89PN");
+}
+
+#[test]
+fn read_zero_sized() -> Result {
+	#[derive(Copy, Clone, Debug, PartialEq)]
+	#[repr(transparent)]
+	struct Marker(());
+
+	unsafe impl bytemuck::Zeroable for Marker { }
+	unsafe impl bytemuck::Pod for Marker { }
+
+	let mut source: &[u8] = &[];
+	let marker: Marker = source.read_data()?;
+	assert_eq!(marker, Marker(()));
+	let array: [Marker; 2] = source.read_data_array()?;
+	assert_eq!(array, [Marker(()); 2]);
+	let vec: Vec<Marker> = source.read_data_vec(3)?;
+	assert_eq!(vec, [Marker(()); 3]);
+	Ok(())
+}
+
+#[test]
+fn read_slice_exact() -> Result {
+	let data = b"\x03abcdef";
+	// Both slices borrow the data rather than the source.
+	let (first, second) = {
+		let mut source = &data[..];
+		let len = source.read_u8()? as usize;
+		(source.read_slice_exact(len)?, source.read_slice_exact(2)?)
+	};
+	assert_eq!((first, second), (&b"abc"[..], &b"de"[..]));
+
+	let mut source = &data[6..];
+	assert_eq!(source.read_slice_exact(2), Err(Error::end(2, 0)));
+	assert_eq!(source, b"f");
+
+	let mut source = StrSource::new("héllo");
+	assert_eq!(source.read_slice_exact(3)?, "hé".as_bytes());
+	assert_eq!(source.as_str(), Some("llo"));
+	Ok(())
+}
+
+#[test]
+fn read_into_uninit() -> Result {
+	use std::mem::MaybeUninit;
+	use data_streams::IterSource;
+
+	let mut buf = [MaybeUninit::uninit(); 4];
+	let mut source = &b"Hello"[..];
+	assert_eq!(source.read_into_uninit(&mut buf)?, b"Hell");
+	assert_eq!(source.read_into_uninit(&mut buf)?, b"o");
+	assert_eq!(source.read_into_uninit(&mut buf)?, b"");
+
+	let mut source = b"Hi".to_vec();
+	assert_eq!(source.read_into_uninit(&mut buf)?, b"Hi");
+	assert!(source.is_empty());
+
+	let mut source = IterSource::new(b"abcde".iter().copied());
+	assert_eq!(source.read_into_uninit(&mut buf)?, b"abcd");
+	Ok(())
+}
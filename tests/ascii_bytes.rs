@@ -0,0 +1,58 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use data_streams::{AsciiError, DataSink, DataSource, Error, Result};
+
+#[test]
+fn read_nominal() -> Result {
+	let mut input = &b"hello world!"[..];
+	assert_eq!(input.read_ascii_bytes(&mut [0; 256])?, b"hello world!");
+	assert_eq!(input, &[]);
+	Ok(())
+}
+
+#[test]
+fn read_first_invalid_byte() {
+	let mut input = std::collections::VecDeque::from(b"ab\xFFc\xFEd".to_vec());
+	let mut buf = [0; 8];
+	assert_eq!(
+		input.read_ascii_bytes(&mut buf),
+		Err(Error::Ascii(AsciiError { invalid_byte: 0xFF, valid_up_to: 2, consumed_count: 6 }))
+	);
+	assert_eq!(&buf[..2], b"ab");
+}
+
+#[test]
+fn write_ascii_bytes() -> Result {
+	let mut sink = Vec::new();
+	sink.write_ascii_bytes(b"GET / HTTP/1.1")?;
+	assert_eq!(sink, b"GET / HTTP/1.1");
+	Ok(())
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic = "bytes should be valid ASCII"]
+fn write_ascii_bytes_invalid() {
+	let _ = Vec::new().write_ascii_bytes(b"caf\xC3\xA9");
+}
+
+#[test]
+fn read_slice_consumes_valid() {
+	let mut input = &b"ab\xFFc"[..];
+	let mut buf = [0; 8];
+	let Err(Error::Ascii(error)) = input.read_ascii_bytes(&mut buf) else {
+		panic!("expected an ASCII error")
+	};
+	assert_eq!(error, AsciiError { invalid_byte: 0xFF, valid_up_to: 2, consumed_count: 2 });
+	assert_eq!(error.valid_bytes(&buf), b"ab");
+	assert_eq!(input, b"\xFFc");
+}
+
+#[test]
+fn split_valid_bytes() {
+	let error = AsciiError { invalid_byte: 0xFF, valid_up_to: 2, consumed_count: 4 };
+	assert_eq!(error.split_valid_bytes(b"ab\xFFcd"), (&b"ab"[..], &b"\xFFc"[..]));
+	assert_eq!(error.unchecked_count(), 1);
+	assert_eq!(Error::from(error).to_string(), "non-ASCII byte 0xFF at index 2");
+}
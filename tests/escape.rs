@@ -0,0 +1,42 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "std")]
+
+use std::io::BufReader;
+use data_streams::{DataSink, DataSource, Error, Escape, EscapingSink, Result, UnescapingSource};
+
+const HDLC: Escape = Escape::new(0x7D, 0x7E, 0x5E);
+
+#[test]
+fn round_trip() -> Result {
+	let payload = [0x7E, 1, 0x7D, 0x7D, 2, 0x7E];
+	let mut sink = EscapingSink::new(Vec::new(), HDLC);
+	sink.write_bytes(&payload)?;
+	sink.write_delimiter()?;
+	sink.write_u16(0x7E7D)?;
+	let bytes = sink.into_inner();
+	assert_eq!(bytes, [0x7D, 0x5E, 1, 0x7D, 0x7D, 0x7D, 0x7D, 2, 0x7D, 0x5E, 0x7E, 0x7D, 0x5E, 0x7D, 0x7D]);
+
+	// A small buffer splits escape sequences across fills.
+	let mut source = UnescapingSource::new(BufReader::with_capacity(3, &bytes[..]), HDLC);
+	assert_eq!(source.read_bytes(&mut [0; 16])?, payload);
+	assert_eq!(source.read_u8(), Err(Error::end(1, 0)));
+	assert!(source.next_frame());
+	assert!(!source.next_frame());
+	assert_eq!(source.read_u16()?, 0x7E7D);
+	assert_eq!(source.read_bytes(&mut [0; 4])?, []);
+	Ok(())
+}
+
+#[test]
+fn overflow() {
+	let mut buf = [0; 4];
+	let mut sink = EscapingSink::new(&mut buf[..], Escape::SLIP);
+	assert_eq!(sink.write_bytes(&[1, 0xC0, 2, 3]), Err(Error::overflow(1, 3)));
+	assert_eq!(buf, [1, 0xDB, 0xDC, 2]);
+
+	let mut buf = [0; 2];
+	let mut sink = EscapingSink::new(&mut buf[..], Escape::SLIP);
+	assert_eq!(sink.write_bytes(&[1, 0xDB]), Err(Error::overflow(1, 1)));
+}
@@ -0,0 +1,91 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use data_streams::{read_array, DataSource, Error, IterSource, Result};
+
+#[test]
+fn read_exact_end() {
+	let mut source = IterSource::new([1, 2, 3].into_iter());
+	assert_eq!(source.read_exact_bytes(&mut [0; 4]), Err(Error::end(4, 0)));
+}
+
+#[test]
+fn read_exact_unknown_lower_bound() -> Result {
+	let mut source = IterSource::new((0..10).filter(|_| true));
+	assert_eq!(source.available(), 0);
+	assert_eq!(source.read_u16()?, 0x0001);
+	assert_eq!(source.read_exact_bytes(&mut [0; 3])?, [2, 3, 4]);
+	assert_eq!(read_array::<4>(&mut source)?, [5, 6, 7, 8]);
+	// The upper bound rules out the read, leaving the last byte unconsumed.
+	assert_eq!(source.read_u16(), Err(Error::end(2, 0)));
+	assert_eq!(source.read_u8()?, 9);
+
+	// Without an upper bound, the bytes actually read are counted.
+	let mut source = IterSource::new(core::iter::successors(Some(1), |&i| (i < 6).then_some(i + 1)));
+	assert_eq!(source.read_exact_bytes(&mut [0; 8]), Err(Error::end(8, 6)));
+	Ok(())
+}
+
+#[test]
+fn skip() -> Result {
+	let mut source = IterSource::new(0..=255);
+	assert_eq!(source.skip(10)?, 10);
+	assert_eq!(source.read_u8()?, 10);
+
+	// Unknown lower bound
+	let mut source = IterSource::new((0..10).filter(|_| true));
+	assert_eq!(source.available(), 0);
+	assert_eq!(source.skip(8)?, 8);
+	assert_eq!(source.skip(8)?, 2);
+	Ok(())
+}
+
+#[test]
+fn skip_short_size_hint() -> Result {
+	// A size hint overstating the length, which iterators may report.
+	struct Overstated(core::ops::Range<u8>);
+
+	impl Iterator for Overstated {
+		type Item = u8;
+
+		fn next(&mut self) -> Option<u8> { self.0.next() }
+		fn size_hint(&self) -> (usize, Option<usize>) { (usize::MAX, None) }
+	}
+
+	let mut source = IterSource::new(Overstated(0..4));
+	assert_eq!(source.skip(8)?, 4);
+	Ok(())
+}
+
+#[test]
+fn size() {
+	let source = IterSource::new(0..16);
+	assert_eq!(source.available(), 16);
+	assert_eq!(source.remaining(), Some(16));
+
+	let source = IterSource::new((0..16).filter(|_| true));
+	assert_eq!(source.available(), 0);
+	assert_eq!(source.remaining(), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn extend_from_source() -> Result {
+	use data_streams::ExtendFromSource;
+
+	let mut buf = vec![0xFF];
+	let mut source = IterSource::new((0..=255).cycle().take(20_000));
+	assert_eq!(buf.extend_from_source(&mut source)?.len(), 20_000);
+	assert_eq!(buf.len(), 20_001);
+	assert!(buf[1..].iter().copied().eq((0..=255).cycle().take(20_000)));
+	Ok(())
+}
+
+#[test]
+fn read_array_dyn() -> Result {
+	let mut source = IterSource::new(1..=6);
+	let source: &mut dyn DataSource = &mut source;
+	assert_eq!(read_array::<4>(source)?, [1, 2, 3, 4]);
+	assert_eq!(read_array::<4>(source), Err(Error::end(4, 0)));
+	Ok(())
+}
@@ -0,0 +1,30 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+use data_streams::{BufferAccess, DataSource, Error, IterSource, PushbackSource, Result};
+
+#[test]
+fn peek_unbounded_iter() -> Result {
+	// Filtering hides the exact length, so the source is read like a raw reader.
+	let mut source = PushbackSource::<_, 4>::new(IterSource::new((1..=6).filter(|_| true)));
+	assert_eq!(source.try_peek_array()?, Some([1, 2, 3, 4]));
+	assert_eq!(source.try_peek_array::<5>(), Err(Error::insufficient_buffer(0, 1)));
+	assert_eq!(source.read_u16()?, 0x0102);
+	assert_eq!(source.try_peek_array()?, Some([3, 4, 5, 6]));
+	source.skip(3)?;
+	assert_eq!(source.try_peek_array::<2>()?, None);
+	assert_eq!(source.read_u8()?, 6);
+	Ok(())
+}
+
+#[test]
+fn unread_shifts_buffer() -> Result {
+	let mut source = PushbackSource::<_, 4>::new(IterSource::new([1, 2, 3].into_iter()));
+	assert_eq!(source.read_u8()?, 1);
+	assert_eq!(source.try_peek_array()?, Some([2, 3]));
+	source.unread(&[0, 1])?;
+	assert_eq!(source.buffer(), [0, 1, 2, 3]);
+	assert_eq!(source.unread(&[0]), Err(Error::insufficient_buffer(0, 1)));
+	assert_eq!(source.read_bytes(&mut [0; 8])?, [0, 1, 2, 3]);
+	Ok(())
+}
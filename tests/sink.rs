@@ -0,0 +1,450 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg_attr(feature = "unstable_borrowed_buf", feature(core_io_borrowed_buf))]
+
+use data_streams::{BoxedSliceSink, CappedVecSink, CountingSink, DataSink, Endian, Error, Result, SliceSink};
+
+#[test]
+fn write_iter_slice() -> Result {
+	let mut buf = [0; 4];
+	let mut sink = &mut buf[..];
+	sink.write_iter([1, 2, 3])?;
+	assert_eq!(sink.len(), 1);
+	assert_eq!(buf, [1, 2, 3, 0]);
+	Ok(())
+}
+
+#[test]
+fn write_iter_overflow() {
+	let mut buf = [0; 100];
+	let mut sink = &mut buf[..];
+	assert_eq!(sink.write_iter(0..200), Err(Error::overflow(100, 100)));
+	assert!(sink.is_empty());
+	assert!(buf.iter().copied().eq(0..100));
+}
+
+#[test]
+fn write_bytes_chunked_overflow() {
+	let mut buf = [0; 7];
+	let mut sink = &mut buf[..];
+	let mut progress = Vec::new();
+	assert_eq!(
+		sink.write_bytes_chunked(&[1; 10], 3, |written| progress.push(written)),
+		Err(Error::overflow(3, 7))
+	);
+	assert!(sink.is_empty());
+	assert_eq!(progress, [3, 6]);
+}
+
+#[test]
+fn write_ints_slice() -> Result {
+	let mut buf = [0; 15];
+	let mut sink = &mut buf[..];
+	sink.write_u16(0x0102)?;
+	sink.write_u32_le(0x0605_0403)?;
+	sink.write_i64(-1)?;
+	assert_eq!(sink.write_u16(0x0A0B), Err(Error::overflow(1, 1)));
+	assert!(sink.is_empty());
+	assert_eq!(buf, [1, 2, 3, 4, 5, 6, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0A]);
+	Ok(())
+}
+
+#[test]
+fn write_ints_vec() -> Result {
+	let mut sink = Vec::new();
+	sink.write_u16(0x0102)?;
+	sink.write_u32_le(0x0605_0403)?;
+	sink.write_i64(-1)?;
+	sink.write_u128_le(7)?;
+	assert_eq!(sink[..14], [1, 2, 3, 4, 5, 6, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+	assert_eq!(sink[14..], 7u128.to_le_bytes());
+	Ok(())
+}
+
+#[test]
+fn reserve_exact() -> Result {
+	fn write_final(sink: &mut impl DataSink, payload: &[u8]) -> Result {
+		sink.reserve_exact(payload.len())?;
+		sink.write_bytes(payload)
+	}
+
+	let mut vec = vec![0; 10];
+	write_final(&mut vec, &[1; 5])?;
+	assert_eq!(vec.capacity(), 15);
+
+	let mut deque = std::collections::VecDeque::from(vec![0; 10]);
+	write_final(&mut deque, &[1; 5])?;
+	assert_eq!(deque.capacity(), 15);
+
+	let mut buf = [0; 2];
+	write_final(&mut &mut buf[..], &[1, 2])?;
+	assert_eq!(buf, [1, 2]);
+	Ok(())
+}
+
+#[test]
+fn reserve() -> Result {
+	fn write_payload(sink: &mut impl DataSink, payload: &[u8]) -> Result {
+		sink.reserve(payload.len())?;
+		sink.write_bytes(payload)
+	}
+
+	let mut vec = Vec::new();
+	write_payload(&mut vec, &[1; 100])?;
+	assert!(vec.capacity() >= 100);
+
+	let mut deque = std::collections::VecDeque::new();
+	write_payload(&mut deque, &[1; 100])?;
+	assert!(deque.capacity() >= 100);
+
+	let mut buf = [0; 4];
+	let mut slice = &mut buf[..];
+	write_payload(&mut slice, &[1, 2])?;
+	assert_eq!(buf, [1, 2, 0, 0]);
+	Ok(())
+}
+
+#[test]
+fn reserve_too_large() {
+	let mut vec = Vec::<u8>::new();
+	assert!(matches!(DataSink::reserve(&mut vec, usize::MAX), Err(Error::Allocation(_))));
+}
+
+#[test]
+fn boxed_slice() -> Result {
+	let mut sink = BoxedSliceSink::from(vec![0xFF; 8].into_boxed_slice());
+	sink.write_u16(0x0102)?;
+	sink.write_utf8("abc")?;
+	assert_eq!(sink.position(), 5);
+	assert_eq!(sink.spare(), 3);
+	assert_eq!(sink.write_u32_le(0x0706_0504), Err(Error::overflow(1, 3)));
+	assert_eq!(sink.spare(), 0);
+	assert_eq!(sink.write_u8(0), Err(Error::overflow(1, 0)));
+	assert_eq!(&*sink.into_inner(), b"\x01\x02abc\x04\x05\x06");
+	Ok(())
+}
+
+#[test]
+fn write_char_slice() -> Result {
+	let mut buf = [0; 6];
+	let mut sink = &mut buf[..];
+	assert_eq!(sink.write_char('é')?, 2);
+	assert_eq!(sink.write_char('🦀')?, 4);
+	assert_eq!(sink.write_char('a'), Err(Error::overflow(1, 0)));
+	assert_eq!(&buf, "é🦀".as_bytes());
+
+	let mut buf = [0; 3];
+	let mut sink = &mut buf[..];
+	assert_eq!(sink.write_char('🦀'), Err(Error::overflow(4, 0)));
+	assert_eq!(sink.len(), 3);
+	Ok(())
+}
+
+#[test]
+fn write_utf8_codepoint_atomic() -> Result {
+	let mut buf = [0; 4];
+	let mut sink = &mut buf[..];
+	sink.write_utf8("ab")?;
+	assert_eq!(sink.write_utf8_codepoint('🍉'), Err(Error::overflow(4, 0)));
+	assert_eq!(sink.len(), 2);
+	assert_eq!(buf, *b"ab\0\0");
+
+	let mut sink = SliceSink::new([0; 4]);
+	sink.write_utf8("ab")?;
+	assert_eq!(sink.write_utf8_codepoint('🍉'), Err(Error::overflow(4, 0)));
+	assert_eq!(sink.filled(), b"ab");
+	sink.write_utf8_codepoint('é')?;
+	assert_eq!(sink.filled(), "abé".as_bytes());
+
+	let mut sink = BoxedSliceSink::from(vec![0; 4].into_boxed_slice());
+	sink.write_utf8("ab")?;
+	assert_eq!(sink.write_utf8_codepoint('🍉'), Err(Error::overflow(4, 0)));
+	assert_eq!(sink.position(), 2);
+	Ok(())
+}
+
+#[test]
+fn write_i8_slice_overflow() {
+	let mut buf = [0; 2];
+	let mut sink = &mut buf[..];
+	assert_eq!(sink.write_i8_slice(&[-1, -2, -3]), Err(Error::overflow(1, 2)));
+	assert_eq!(buf, [0xFF, 0xFE]);
+}
+
+#[test]
+fn write_ints_iter() -> Result {
+	use data_streams::{Endian, GenericDataSink};
+
+	let mut sink = Vec::new();
+	sink.write_ints((0..3u32).map(|n| n << 8), Endian::Big)?;
+	sink.write_ints([0x0102i16], Endian::Little)?;
+	assert_eq!(sink, [0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 2, 1]);
+
+	let mut buf = [0; 5];
+	let mut sink = &mut buf[..];
+	assert!(matches!(
+		sink.write_ints([1u16, 2, 3], Endian::Big),
+		Err(Error::Overflow { .. })
+	));
+	assert_eq!(buf, [0, 1, 0, 2, 0]);
+	Ok(())
+}
+
+#[test]
+fn slice_sink_mapped_storage() -> Result {
+	use data_streams::{DataSource, SliceSink};
+
+	// Stands in for a memory mapping, which only exposes its bytes through
+	// AsRef and AsMut.
+	struct Mapping(Vec<u8>);
+
+	impl AsRef<[u8]> for Mapping {
+		fn as_ref(&self) -> &[u8] { &self.0 }
+	}
+
+	impl AsMut<[u8]> for Mapping {
+		fn as_mut(&mut self) -> &mut [u8] { &mut self.0 }
+	}
+
+	let mut sink = SliceSink::new(Mapping(vec![0; 10]));
+	sink.write_u64_le(u64::MAX)?;
+	assert_eq!(sink.spare(), 2);
+	assert_eq!(sink.write_u32(1), Err(Error::overflow(2, 2)));
+	assert_eq!(sink.position(), 10);
+
+	let mut source = std::io::Cursor::new(sink.into_inner());
+	assert_eq!(source.read_u64_le()?, u64::MAX);
+	assert_eq!(source.remaining(), Some(2));
+	Ok(())
+}
+
+#[cfg(feature = "unstable_specialization")]
+#[test]
+fn write_owned_bytes_moves_into_empty() -> Result {
+	use std::collections::VecDeque;
+	use data_streams::VecSink;
+
+	let buf = vec![1; 64];
+	let ptr = buf.as_ptr();
+	let mut sink = Vec::new();
+	sink.write_owned_bytes(buf)?;
+	assert_eq!(sink.as_ptr(), ptr);
+	sink.write_owned_bytes(vec![2; 2])?;
+	assert_eq!(sink.len(), 66);
+	assert_eq!(sink[63..], [1, 2, 2]);
+
+	let buf = vec![1; 64];
+	let ptr = buf.as_ptr();
+	let mut sink = VecDeque::new();
+	sink.write_owned_bytes(buf)?;
+	assert_eq!(sink.as_slices().0.as_ptr(), ptr);
+	Ok(())
+}
+
+#[test]
+fn write_int_str() -> Result {
+	let mut sink = Vec::new();
+	sink.write_int_str(0u8)?;
+	sink.write_int_str(i128::MIN)?;
+	assert_eq!(sink, b"0-170141183460469231731687303715884105728");
+
+	let mut buf = [0; 4];
+	let mut sink = &mut buf[..];
+	assert_eq!(sink.write_int_str(-12345), Err(Error::overflow(2, 4)));
+	assert_eq!(&buf, b"-123");
+	Ok(())
+}
+
+#[test]
+fn int_str_round_trip() -> Result {
+	use std::num::IntErrorKind::{Empty, InvalidDigit, NegOverflow, PosOverflow};
+	use data_streams::DataSource;
+
+	let mut sink = Vec::new();
+	for value in [0, -1, i64::MIN, i64::MAX] {
+		sink.write_int_str(value)?;
+		sink.write_u8(b',')?;
+	}
+	let mut source = &sink[..];
+	for value in [0, -1, i64::MIN, i64::MAX] {
+		assert_eq!(source.read_int_str::<i64>(b',')?, value);
+	}
+	assert_eq!(source.read_int_str::<i64>(b','), Err(Error::end(1, 0)));
+
+	let mut source = &b"+7\n\n-\n256\n-128\n-129\n-1\n1x\n"[..];
+	assert_eq!(source.read_int_str::<u8>(b'\n')?, 7);
+	assert_eq!(source.read_int_str::<u8>(b'\n'), Err(Error::parse_int(Empty)));
+	assert_eq!(source.read_int_str::<i8>(b'\n'), Err(Error::parse_int(InvalidDigit)));
+	assert_eq!(source.read_int_str::<u8>(b'\n'), Err(Error::parse_int(PosOverflow)));
+	assert_eq!(source.read_int_str::<i8>(b'\n')?, i8::MIN);
+	assert_eq!(source.read_int_str::<i8>(b'\n'), Err(Error::parse_int(NegOverflow)));
+	assert_eq!(source.read_int_str::<u8>(b'\n'), Err(Error::parse_int(InvalidDigit)));
+	assert_eq!(source, b"1\n1x\n");
+	assert_eq!(source.read_int_str::<u8>(b'\n')?, 1);
+	assert_eq!(source.read_int_str::<u8>(b'\n'), Err(Error::parse_int(InvalidDigit)));
+	assert_eq!(source, b"\n");
+	Ok(())
+}
+
+#[test]
+fn write_num_newtype() -> Result {
+	// A wrapper which isn't Pod, but has a byte representation.
+	struct Id(u32);
+
+	impl num_traits::ToBytes for Id {
+		type Bytes = [u8; 4];
+		fn to_be_bytes(&self) -> [u8; 4] { self.0.to_be_bytes() }
+		fn to_le_bytes(&self) -> [u8; 4] { self.0.to_le_bytes() }
+	}
+
+	let mut sink = Vec::new();
+	sink.write_num(&Id(0x0102_0304), Endian::Big)?;
+	sink.write_num(&Id(0x0102_0304), Endian::Little)?;
+	assert_eq!(sink, [1, 2, 3, 4, 4, 3, 2, 1]);
+	Ok(())
+}
+
+#[test]
+fn write_padding_past_target() -> Result {
+	let mut sink = CountingSink::new(Vec::new());
+	sink.write_bytes(b"too long")?;
+	assert_eq!(sink.write_padding_to(4, b' '), Err(Error::overflow(4, 0)));
+	sink.write_padding_to(8, b' ')?;
+	sink.write_padding_to(200, b' ')?;
+	assert_eq!(sink.count(), 200);
+	assert_eq!(sink.get_ref().len(), 200);
+	Ok(())
+}
+
+#[test]
+fn write_padding_overflow() {
+	let mut buf = [0; 6];
+	let mut sink = CountingSink::new(&mut buf[..]);
+	assert_eq!(sink.write_padding_to(8, 1), Err(Error::overflow(2, 6)));
+	assert_eq!(sink.count(), 6);
+	assert_eq!(buf, [1; 6]);
+}
+
+#[test]
+fn counting_write_char_overflow() {
+	let mut buf = [0; 6];
+	let mut sink = CountingSink::new(&mut buf[..]);
+	assert_eq!(sink.write_char('a'), Ok(1));
+	assert_eq!(sink.write_char('🦀'), Ok(4));
+	assert_eq!(sink.write_char('é'), Err(Error::overflow(2, 0)));
+	assert_eq!(sink.count(), 5);
+
+	let mut sink = CountingSink::new(SliceSink::new([0; 6]));
+	assert_eq!(sink.write_utf8("ab"), Ok(()));
+	assert_eq!(sink.write_char('🦀'), Ok(4));
+	assert_eq!(sink.write_char('é'), Err(Error::overflow(2, 0)));
+	assert_eq!(sink.count(), 6);
+	assert_eq!(sink.get_ref().filled(), "ab🦀".as_bytes());
+}
+
+#[test]
+fn capped_vec_overflow() -> Result {
+	let mut sink = CappedVecSink::new(8);
+	sink.reserve(1024)?;
+	assert!(sink.get_ref().capacity() < 1024);
+	sink.write_bytes(b"hello")?;
+	assert_eq!(sink.write_char('🦀'), Err(Error::overflow(4, 0)));
+	assert_eq!(sink.write_utf8(", World!"), Err(Error::overflow(5, 3)));
+	assert_eq!(sink.spare(), 0);
+	assert_eq!(sink.write_u8(0), Err(Error::overflow(1, 0)));
+	assert_eq!(sink.into_inner(), b"hello, W");
+	Ok(())
+}
+
+#[cfg(feature = "unstable_borrowed_buf")]
+#[test]
+fn borrowed_buf_overflow() {
+	use core::io::BorrowedBuf;
+	use core::mem::MaybeUninit;
+
+	let mut storage = [MaybeUninit::uninit(); 4];
+	let mut buf = BorrowedBuf::from(&mut storage[..]);
+	assert_eq!(buf.write_bytes(b"abc"), Ok(()));
+	assert_eq!(buf.write_u8(b'd'), Ok(()));
+	assert_eq!(buf.write_u8(b'e'), Err(Error::overflow(1, 0)));
+	assert_eq!(buf.len(), 4);
+
+	let mut buf = BorrowedBuf::from(&mut storage[..]);
+	assert_eq!(buf.write_bytes(b"abcde"), Err(Error::overflow(1, 4)));
+	assert_eq!(buf.filled(), b"abcd");
+}
+
+#[cfg(feature = "std")]
+mod overflow {
+	use std::io::Cursor;
+	use proptest::arbitrary::any;
+	use proptest::collection::vec;
+	use proptest::{prop_assert_eq, proptest};
+	use super::*;
+
+	/// Checks that an overflowing sink was filled with exactly the bytes that fit,
+	/// and that the remaining count is precise.
+	fn check_overflow(data: &[u8], capacity: usize, result: &Result, written: &[u8]) {
+		let fit = data.len().min(capacity);
+		if fit == data.len() {
+			assert_eq!(result, &Ok(()));
+		} else {
+			assert_eq!(result, &Err(Error::overflow(data.len() - fit, fit)));
+		}
+		assert_eq!(written, &data[..fit]);
+	}
+
+	proptest! {
+		#[test]
+		fn slice(data in vec(any::<u8>(), 0..=64), capacity in 0usize..=64) {
+			let mut buf = vec![0; capacity];
+			let mut sink = &mut buf[..];
+			let result = sink.write_bytes(&data);
+			let written = capacity - sink.len();
+			check_overflow(&data, capacity, &result, &buf[..written]);
+		}
+	}
+
+	proptest! {
+		#[test]
+		fn cursor(data in vec(any::<u8>(), 0..=64), capacity in 0usize..=64, start in 0usize..=8) {
+			let mut buf = vec![0xFF; capacity + start];
+			let mut sink = Cursor::new(&mut buf[..]);
+			sink.set_position(start as u64);
+			let result = sink.write_bytes(&data);
+			let end = sink.position() as usize;
+			prop_assert_eq!(&buf[..start], &vec![0xFF; start][..]);
+			check_overflow(&data, capacity, &result, &buf[start..end]);
+		}
+	}
+
+	#[cfg(feature = "unstable_uninit_slice")]
+	proptest! {
+		#[test]
+		fn uninit_slice(data in vec(any::<u8>(), 0..=64), capacity in 0usize..=64) {
+			use core::mem::MaybeUninit;
+
+			let mut buf = vec![MaybeUninit::new(0); capacity];
+			let mut sink = &mut buf[..];
+			let result = sink.write_bytes(&data);
+			let written = capacity - sink.len();
+			// Safety: all elements were initialized above.
+			let buf = buf.iter().map(|b| unsafe { b.assume_init() }).collect::<Vec<u8>>();
+			check_overflow(&data, capacity, &result, &buf[..written]);
+		}
+	}
+
+	#[cfg(feature = "unstable_borrowed_buf")]
+	proptest! {
+		#[test]
+		fn borrowed_cursor(data in vec(any::<u8>(), 0..=64), capacity in 0usize..=64) {
+			use core::io::BorrowedBuf;
+
+			let mut buf = vec![0; capacity];
+			let mut buf = BorrowedBuf::from(&mut buf[..]);
+			let result = buf.unfilled().write_bytes(&data);
+			check_overflow(&data, capacity, &result, buf.filled());
+		}
+	}
+}
@@ -0,0 +1,742 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "std")]
+
+use std::io::{BufReader, Cursor, Read};
+use data_streams::{BufferAccess, DataSink, DataSource, Error, GenericDataSource, Result};
+
+mod chain {
+	use super::*;
+
+	#[test]
+	fn read_across_halves() -> Result {
+		let mut source = Read::chain(&b"head"[..], &b"er body"[..]);
+		assert_eq!(source.available(), 4);
+		assert_eq!(source.read_exact_bytes(&mut [0; 6])?, b"header");
+		assert_eq!(source.available(), 5);
+		assert_eq!(source.read_bytes(&mut [0; 16])?, b" body");
+		Ok(())
+	}
+
+	#[test]
+	fn skip_across_halves() -> Result {
+		let mut source = Read::chain(&b"head"[..], &b"er body"[..]);
+		assert_eq!(source.skip(7)?, 7);
+		assert_eq!(source.read_bytes(&mut [0; 16])?, b"body");
+		Ok(())
+	}
+
+	#[test]
+	fn read_exact_end() {
+		let mut source = Read::chain(&b"head"[..], &b"er"[..]);
+		assert_eq!(source.read_exact_bytes(&mut [0; 7]), Err(Error::end(7, 6)));
+	}
+
+	/// A source reading at most two bytes at a time, like a pipe.
+	struct Trickle(&'static [u8]);
+
+	impl Read for Trickle {
+		fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+			let len = buf.len().min(2);
+			Read::read(&mut self.0, &mut buf[..len])
+		}
+	}
+
+	impl DataSource for Trickle {
+		fn available(&self) -> usize { 0 }
+		fn request(&mut self, _: usize) -> Result<bool> { Ok(false) }
+		fn skip(&mut self, count: usize) -> Result<usize> {
+			let count = count.min(2).min(self.0.len());
+			self.0 = &self.0[count..];
+			Ok(count)
+		}
+		fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+			let len = buf.len().min(2);
+			self.0.read_bytes(&mut buf[..len])
+		}
+	}
+
+	#[test]
+	fn non_greedy_first() -> Result {
+		let mut source = Read::chain(Trickle(b"head"), &b"er body"[..]);
+		assert_eq!(source.read_bytes(&mut [0; 8])?, b"he");
+		assert_eq!(source.read_exact_bytes(&mut [0; 4])?, b"ader");
+		assert_eq!(source.read_bytes(&mut [0; 8])?, b" body");
+
+		let mut source = Read::chain(Trickle(b"head"), &b"er body"[..]);
+		assert_eq!(source.skip(7)?, 7);
+		assert_eq!(source.read_bytes(&mut [0; 8])?, b"body");
+		Ok(())
+	}
+}
+
+mod buf_reader {
+	use super::*;
+
+	#[test]
+	fn read_data() -> Result {
+		let bytes: Vec<u8> = (0..=255).cycle().take(1040).collect();
+		let mut source = BufReader::new(&bytes[..]);
+		let value: [u8; 1024] = source.read_data()?;
+		assert_eq!(value[..], bytes[..1024]);
+		assert_eq!(source.read_data(), Ok(u128::from_ne_bytes(bytes[1024..].try_into().unwrap())));
+		assert_eq!(GenericDataSource::<u8>::read_data(&mut source), Err(Error::end(1, 0)));
+		Ok(())
+	}
+
+	#[test]
+	fn skip_past_capacity() -> Result {
+		let mut source = BufReader::with_capacity(4, &b"Hello, World!"[..]);
+		source.fill_buffer()?;
+		assert_eq!(source.skip(7)?, 7);
+		assert_eq!(source.read_bytes(&mut [0; 8])?, b"World!");
+		assert_eq!(source.skip(4)?, 0);
+
+		let mut source = BufReader::with_capacity(4, &b"Hello"[..]);
+		assert_eq!(source.skip(8)?, 5);
+		Ok(())
+	}
+
+	#[test]
+	fn skip_error_mid_stream() -> Result {
+		use std::io::ErrorKind;
+
+		/// Fails every read, as if the connection dropped.
+		struct Failing;
+
+		impl Read for Failing {
+			fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+				Err(ErrorKind::ConnectionReset.into())
+			}
+		}
+
+		// The error surfaces from the refill after the first bytes are skipped,
+		// rather than being reported as a short skip.
+		let mut source = BufReader::with_capacity(2, Read::chain(&b"abc"[..], Failing));
+		assert_eq!(source.skip(8), Err(Error::Io(ErrorKind::ConnectionReset.into())));
+		assert_eq!(source.skip(1), Err(Error::Io(ErrorKind::ConnectionReset.into())));
+
+		let mut source = Read::take(BufReader::with_capacity(2, Read::chain(&b"abc"[..], Failing)), 8);
+		assert_eq!(source.skip(8), Err(Error::Io(ErrorKind::ConnectionReset.into())));
+		Ok(())
+	}
+
+	#[test]
+	fn read_u8_across_fills() -> Result {
+		let mut source = BufReader::with_capacity(2, &b"ab\xFF"[..]);
+		assert_eq!(source.read_u8()?, b'a');
+		assert_eq!(source.read_u8()?, b'b');
+		assert_eq!(source.read_i8()?, -1);
+		assert_eq!(source.read_u8(), Err(Error::end(1, 0)));
+
+		let mut source = Cursor::new(b"a");
+		assert_eq!(source.read_u8()?, b'a');
+		assert_eq!(source.read_u8(), Err(Error::end(1, 0)));
+		assert_eq!(source.position(), 1);
+		Ok(())
+	}
+
+	#[test]
+	fn read_bytes_across_fills() -> Result {
+		let mut source = BufReader::with_capacity(2, &b"abcde"[..]);
+		assert_eq!(source.read_bytes(&mut [0; 8])?, b"abcde");
+		Ok(())
+	}
+
+	#[test]
+	fn read_exact_end() {
+		let mut source = BufReader::with_capacity(2, &b"abc"[..]);
+		assert_eq!(source.read_exact_bytes(&mut [0; 5]), Err(Error::end(5, 3)));
+	}
+
+	#[cfg(feature = "utf8")]
+	#[test]
+	fn read_utf8_split_codepoint() -> Result {
+		struct Chunked<'a>(&'a [u8]);
+
+		impl Read for Chunked<'_> {
+			fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+				let len = buf.len().min(self.0.len()).min(2);
+				buf[..len].copy_from_slice(&self.0[..len]);
+				self.0 = &self.0[len..];
+				Ok(len)
+			}
+		}
+
+		let mut source = BufReader::with_capacity(2, Chunked("a🦀b".as_bytes()));
+		assert_eq!(source.read_utf8(&mut [0; 8])?, "a🦀b");
+
+		let mut source = BufReader::with_capacity(2, Chunked("🦀".as_bytes()));
+		assert_eq!(source.read_utf8(&mut [0; 4])?, "🦀");
+		Ok(())
+	}
+
+	#[test]
+	fn drain_to_sink() -> Result {
+		let mut source = BufReader::with_capacity(2, &b"Hello, World!"[..]);
+		let mut sink = Vec::new();
+		assert_eq!(source.drain_to_sink(&mut sink, 7)?, 7);
+		assert_eq!(sink, b"Hello, ");
+		assert_eq!(source.drain_to_sink(&mut sink, 16)?, 6);
+		assert_eq!(sink, b"Hello, World!");
+		Ok(())
+	}
+
+	#[test]
+	fn drain_to_sink_overflow() {
+		let mut source = BufReader::with_capacity(4, &b"Hello, World!"[..]);
+		let mut buf = [0; 6];
+		let mut sink = &mut buf[..];
+		assert_eq!(source.drain_to_sink(&mut sink, 8), Err(Error::overflow(2, 6)));
+		assert_eq!(&buf, b"Hello,");
+		assert_eq!(source.read_bytes(&mut [0; 8]), Ok(&b" World!"[..]));
+	}
+
+	#[cfg(feature = "unstable_specialization")]
+	#[test]
+	fn read_aligned_split_value() -> Result {
+		let bytes: Vec<u8> = (0..20).collect();
+		let mut source = BufReader::with_capacity(5, &bytes[..]);
+		assert_eq!(source.read_aligned_bytes(&mut [0; 11], 2)?, &bytes[..10]);
+		assert_eq!(source.read_aligned_bytes(&mut [0; 8], 4)?, &bytes[10..18]);
+		assert_eq!(source.read_bytes(&mut [0; 4])?, &bytes[18..]);
+		Ok(())
+	}
+
+	#[test]
+	fn peek_int() -> Result {
+		let mut source = BufReader::with_capacity(4, &b"\x01\x02\x03\x04\x05"[..]);
+		assert_eq!(source.peek_int::<u16>()?, 0x0102);
+		assert_eq!(source.peek_int::<u32>()?, 0x0102_0304);
+		assert_eq!(source.read_u8()?, 1);
+		assert!(matches!(source.peek_data::<u32>(), Err(Error::InsufficientBuffer { .. })));
+		assert_eq!(source.read_bytes(&mut [0; 8])?, b"\x02\x03\x04\x05");
+		Ok(())
+	}
+
+	#[test]
+	fn empty_reads_untouched() -> Result {
+		struct Untouched;
+
+		impl Read for Untouched {
+			fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+				panic!("stream read")
+			}
+		}
+
+		let mut source = BufReader::new(Untouched);
+		assert_eq!(source.read_bytes(&mut [])?, []);
+		assert_eq!(source.read_exact_bytes(&mut [])?, []);
+		assert_eq!(source.read_aligned_bytes(&mut [], 4)?, []);
+		Ok(())
+	}
+
+	#[test]
+	fn read_bytes_stops_when_full() -> Result {
+		// Fails the next read once the first has filled the slice.
+		struct Once(bool);
+
+		impl Read for Once {
+			fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+				assert!(!self.0, "stream read after the slice was filled");
+				self.0 = true;
+				buf[..4].copy_from_slice(b"abcd");
+				Ok(4)
+			}
+		}
+
+		let mut source = BufReader::with_capacity(2, Once(false));
+		assert_eq!(source.read_bytes(&mut [0; 4])?, b"abcd");
+		Ok(())
+	}
+
+	#[test]
+	fn read_exact_resumable() -> Result {
+		use std::io::ErrorKind;
+
+		// Returns each chunk, then blocks once before the next.
+		struct NonBlocking(Vec<&'static [u8]>, bool);
+
+		impl Read for NonBlocking {
+			fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+				self.1 = !self.1;
+				if !self.1 {
+					return Err(ErrorKind::WouldBlock.into())
+				}
+				let Some(chunk) = self.0.pop() else { return Ok(0) };
+				buf[..chunk.len()].copy_from_slice(chunk);
+				Ok(chunk.len())
+			}
+		}
+
+		let mut source = BufReader::new(NonBlocking(vec![b"World!", b"Hello, "], false));
+		let mut buf = [0; 13];
+		let mut filled = 0;
+		let result = source.read_exact_bytes_resumable(&mut buf, &mut filled);
+		assert!(matches!(result, Err(Error::Io(ref error)) if error.kind() == ErrorKind::WouldBlock));
+		assert_eq!(filled, 7);
+		assert_eq!(source.read_exact_bytes_resumable(&mut buf, &mut filled)?, b"Hello, World!");
+		Ok(())
+	}
+
+	#[test]
+	fn fill_exact() -> Result {
+		let mut source = BufReader::with_capacity(4, &b"Hello"[..]);
+		assert_eq!(source.fill_exact(3)?, b"Hell");
+		assert!(matches!(source.fill_exact(5), Err(Error::InsufficientBuffer { .. })));
+		source.drain_buffer(4);
+		assert_eq!(source.fill_exact(1)?, b"o");
+		source.drain_buffer(1);
+		assert_eq!(source.fill_exact(1), Err(Error::end(1, 0)));
+
+		// The end is reached with some bytes buffered.
+		let mut source = BufReader::new(&b"abc"[..]);
+		assert_eq!(source.fill_exact(5), Err(Error::end(5, 3)));
+		assert_eq!(source.fill_exact(3)?, b"abc");
+		Ok(())
+	}
+
+	#[test]
+	fn request_fixed_buffer() {
+		let mut source = BufReader::with_capacity(2, &b"Hello"[..]);
+		assert_eq!(source.request(4), Err(Error::insufficient_buffer(2, 4)));
+	}
+
+	#[test]
+	fn read_data_small_buffer() -> Result {
+		let mut source = BufReader::with_capacity(2, &b"\x01\x02\x03\x04"[..]);
+		let value: u32 = source.read_int()?;
+		assert_eq!(value, 0x0102_0304);
+		Ok(())
+	}
+}
+
+mod progress {
+	use super::*;
+
+	#[test]
+	fn read_exact_bytes_with() -> Result {
+		let mut source = Read::chain(&b"head"[..], &b"er body"[..]);
+		let mut progress = Vec::new();
+		let mut buf = [0; 10];
+		let read = source.read_exact_bytes_with(&mut buf, |count| progress.push(count))?;
+		assert_eq!(read, b"header bod");
+		assert_eq!(progress, [4, 10]);
+		Ok(())
+	}
+
+	#[test]
+	fn read_exact_bytes_with_end() {
+		let mut source = Read::chain(&b"abcd"[..], &b"ef"[..]);
+		let mut progress = Vec::new();
+		assert_eq!(
+			source.read_exact_bytes_with(&mut [0; 8], |count| progress.push(count)),
+			Err(Error::end(8, 6))
+		);
+		assert_eq!(progress, [4, 6]);
+	}
+}
+
+mod cursor_source {
+	use super::*;
+	use data_streams::markers::source::SourceSize;
+
+	#[test]
+	fn available_is_remaining() {
+		let mut source = Cursor::new(b"Hello");
+		for (position, expected) in [(0, 5), (2, 3), (5, 0), (8, 0)] {
+			source.set_position(position);
+			assert_eq!(source.available(), expected, "position {position}");
+			assert_eq!(source.buffer().len(), expected, "position {position}");
+			assert_eq!(source.remaining(), Some(expected as u64), "position {position}");
+			assert_eq!(source.upper_bound(), Some(expected as u64), "position {position}");
+		}
+	}
+
+	#[test]
+	fn request_past_remaining() -> Result {
+		let mut source = Cursor::new(b"Hello");
+		source.set_position(1);
+		assert!(source.request(4)?);
+		source.require(4)?;
+		for count in [5, usize::MAX] {
+			assert!(!source.request(count)?, "count {count}");
+			assert_eq!(source.require(count), Err(Error::end(count, 0)), "count {count}");
+		}
+		assert_eq!(source.position(), 1);
+		Ok(())
+	}
+
+	#[test]
+	fn skip_mid_stream() -> Result {
+		let mut source = Cursor::new(b"Hello");
+		source.set_position(2);
+		assert_eq!(source.skip(8)?, 3);
+		assert_eq!(source.position(), 5);
+		Ok(())
+	}
+
+	#[test]
+	fn read_exact_bytes() -> Result {
+		let mut source = Cursor::new(b"Hello, World!");
+		source.set_position(7);
+		assert_eq!(source.read_exact_bytes(&mut [0; 5])?, b"World");
+		assert_eq!(source.position(), 12);
+		Ok(())
+	}
+
+	#[test]
+	fn read_exact_bytes_end() {
+		let mut source = Cursor::new(b"Hello");
+		source.set_position(2);
+		assert_eq!(source.read_exact_bytes(&mut [0; 4]), Err(Error::end(4, 3)));
+		assert_eq!(source.position(), 5);
+	}
+
+	#[test]
+	fn read_slice_exact() -> Result {
+		use data_streams::SliceSource;
+
+		let data = b"Hello, World!";
+		// The slice borrows the data, outliving the source.
+		let slice = {
+			let mut source = Cursor::new(&data[..]);
+			source.set_position(7);
+			let slice = source.read_slice_exact(5)?;
+			assert_eq!(source.position(), 12);
+			assert_eq!(source.read_slice_exact(2), Err(Error::end(2, 0)));
+			assert_eq!(source.position(), 12);
+			slice
+		};
+		assert_eq!(slice, b"World");
+		Ok(())
+	}
+}
+
+mod cursor_sink {
+	use super::*;
+
+	#[test]
+	fn write_slice_at_position() -> Result {
+		let mut buf = *b"abcdefgh";
+		let mut sink = Cursor::new(&mut buf[..]);
+		sink.set_position(2);
+		sink.write_bytes(b"XYZ")?;
+		assert_eq!(sink.position(), 5);
+		assert_eq!(&buf, b"abXYZfgh");
+		Ok(())
+	}
+
+	#[test]
+	fn write_slice_overflow() {
+		let mut buf = *b"abcdefgh";
+		let mut sink = Cursor::new(&mut buf[..]);
+		sink.set_position(6);
+		assert_eq!(sink.write_bytes(b"XYZ"), Err(Error::overflow(1, 2)));
+		assert_eq!(&buf, b"abcdefXY");
+	}
+
+	#[test]
+	fn write_vec_at_position() -> Result {
+		let mut buf = b"abcdef".to_vec();
+		let mut sink = Cursor::new(&mut buf);
+		sink.set_position(4);
+		sink.write_bytes(b"XYZ")?;
+		assert_eq!(sink.position(), 7);
+		assert_eq!(buf, b"abcdXYZ");
+		Ok(())
+	}
+
+	#[test]
+	fn write_vec_past_end() -> Result {
+		let mut buf = b"ab".to_vec();
+		let mut sink = Cursor::new(&mut buf);
+		sink.set_position(4);
+		sink.write_u16(0x5859)?;
+		assert_eq!(buf, b"ab\0\0XY");
+		Ok(())
+	}
+}
+
+mod remaining {
+	use super::*;
+
+	#[test]
+	fn cursor() {
+		let mut source = Cursor::new(b"Hello, World!");
+		assert_eq!(source.remaining(), Some(13));
+		source.set_position(7);
+		assert_eq!(source.remaining(), Some(6));
+		source.set_position(20);
+		assert_eq!(source.remaining(), Some(0));
+	}
+
+	#[test]
+	fn take() {
+		let source = Read::take(&b"Hello, World!"[..], 5);
+		assert_eq!(source.remaining(), Some(5));
+		let source = Read::take(&b"Hello"[..], 10);
+		assert_eq!(source.remaining(), Some(5));
+	}
+
+	#[test]
+	fn chain() {
+		let source = Read::chain(&b"head"[..], &b"er"[..]);
+		assert_eq!(source.remaining(), Some(6));
+		let source = Read::chain(&b"head"[..], BufReader::new(&b"er"[..]));
+		assert_eq!(source.remaining(), None);
+	}
+}
+
+mod take {
+	use super::*;
+
+	fn source() -> std::io::Take<BufReader<&'static [u8]>> {
+		Read::take(BufReader::with_capacity(4, &b"Hello, World!"[..]), 5)
+	}
+
+	#[test]
+	fn read_exact_under_limit() -> Result {
+		let mut source = source();
+		assert_eq!(source.read_exact_bytes(&mut [0; 4])?, b"Hell");
+		assert_eq!(source.read_bytes(&mut [0; 8])?, b"o");
+		Ok(())
+	}
+
+	#[test]
+	fn skip_to_limit() -> Result {
+		let mut source = source();
+		assert_eq!(source.skip(8)?, 5);
+		assert_eq!(source.read_bytes(&mut [0; 8])?, b"");
+		Ok(())
+	}
+
+	#[test]
+	fn read_exact_at_limit() -> Result {
+		let mut source = source();
+		assert_eq!(source.read_exact_bytes(&mut [0; 5])?, b"Hello");
+		assert_eq!(source.read_bytes(&mut [0; 8])?, b"");
+		Ok(())
+	}
+
+	#[test]
+	fn read_exact_past_limit() {
+		let mut source = source();
+		assert_eq!(source.read_exact_bytes(&mut [0; 6]), Err(Error::end(6, 5)));
+	}
+
+	#[test]
+	fn read_bytes_limit() -> Result {
+		let mut source = source();
+		assert_eq!(source.read_bytes(&mut [0; 16])?, b"Hello");
+		assert_eq!(source.available(), 0);
+		Ok(())
+	}
+
+	#[cfg(feature = "unstable_specialization")]
+	#[test]
+	fn read_to_end_at_limit() -> Result {
+		use data_streams::VecSource;
+
+		let bytes: Vec<u8> = (0..100).collect();
+		let mut inner = BufReader::with_capacity(16, &bytes[..]);
+		let mut buf = Vec::new();
+		let mut source = Read::take(&mut inner, 10);
+		assert_eq!(VecSource::read_to_end(&mut source, &mut buf)?, &bytes[..10]);
+		assert_eq!(VecSource::read_to_end(&mut source, &mut buf)?, b"");
+		assert_eq!(VecSource::read_to_end(&mut inner, &mut buf)?, &bytes[10..]);
+		Ok(())
+	}
+}
+
+mod buf_writer {
+	use super::*;
+	use std::io::{BufWriter, Write};
+
+	#[test]
+	fn try_write_would_block() -> Result {
+		use std::io::ErrorKind;
+
+		// Accepts bytes up to a limit, then blocks.
+		struct Limited(Vec<u8>, usize);
+
+		impl Write for Limited {
+			fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+				let len = buf.len().min(self.1 - self.0.len());
+				if len == 0 {
+					return Err(ErrorKind::WouldBlock.into())
+				}
+				self.0.extend_from_slice(&buf[..len]);
+				Ok(len)
+			}
+
+			fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+		}
+
+		let mut sink = BufWriter::with_capacity(2, Limited(Vec::new(), 4));
+		assert_eq!(sink.try_write_bytes(b"abcdef")?, 4);
+		assert_eq!(sink.try_write_bytes(b"ef")?, 0);
+		sink.get_mut().1 = 6;
+		assert_eq!(sink.try_write_bytes(b"ef")?, 2);
+		assert_eq!(sink.get_ref().0, b"abcdef");
+		Ok(())
+	}
+
+	#[test]
+	fn flush_guard_on_drop() -> Result {
+		let mut sink = BufWriter::new(Vec::new());
+		{
+			let mut guard = sink.flush_guard();
+			guard.write_utf8("Hello")?;
+			assert!(guard.get_ref().is_empty());
+		}
+		assert_eq!(sink.get_ref(), b"Hello");
+		Ok(())
+	}
+
+	#[test]
+	fn flush_guard_finish() {
+		struct Failing;
+
+		impl Write for Failing {
+			fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+				Err(std::io::ErrorKind::BrokenPipe.into())
+			}
+
+			fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+		}
+
+		let mut sink = BufWriter::new(Failing);
+		let mut guard = sink.flush_guard();
+		guard.write_u8(1).unwrap();
+		assert!(matches!(guard.finish(), Err(Error::Io(_))));
+	}
+}
+
+mod tcp {
+	use super::*;
+	use std::net::{TcpListener, TcpStream};
+
+	#[test]
+	fn write_framed() -> Result {
+		let listener = TcpListener::bind("127.0.0.1:0")?;
+		let mut sink = TcpStream::connect(listener.local_addr()?)?;
+		let (stream, _) = listener.accept()?;
+
+		sink.write_u16(5)?;
+		sink.write_utf8("Hello")?;
+		drop(sink);
+
+		let mut source = BufReader::new(stream);
+		let len = source.read_u16()? as usize;
+		assert_eq!(source.read_exact_bytes(&mut [0; 16][..len])?, b"Hello");
+		assert_eq!(source.read_bytes(&mut [0; 1])?, []);
+		Ok(())
+	}
+}
+
+mod fs {
+	use super::*;
+	use data_streams::markers::source::SourceSize;
+
+	#[test]
+	fn create_and_open() -> Result {
+		let path = std::env::temp_dir().join(format!("data-streams-fs-{}", std::process::id()));
+		let mut sink = data_streams::fs::create(&path)?;
+		sink.write_utf8("Hello, World!")?;
+		sink.flush()?;
+
+		let mut source = data_streams::fs::open(&path)?;
+		assert_eq!(source.upper_bound(), Some(13));
+		assert_eq!(source.read_exact_bytes(&mut [0; 5])?, b"Hello");
+		// The rest of the file is buffered, and still counted.
+		assert_eq!(source.upper_bound(), Some(8));
+		assert_eq!(source.read_bytes(&mut [0; 16])?, b", World!");
+		assert_eq!(source.upper_bound(), Some(0));
+		std::fs::remove_file(path)?;
+		Ok(())
+	}
+}
+
+mod udp {
+	use super::*;
+	use std::net::UdpSocket;
+	use data_streams::BufferedDatagramSink;
+
+	#[test]
+	fn buffered_message() -> Result {
+		let receiver = UdpSocket::bind("127.0.0.1:0")?;
+		let sender = UdpSocket::bind("127.0.0.1:0")?;
+		sender.connect(receiver.local_addr()?)?;
+
+		let mut sink = BufferedDatagramSink::new(&sender);
+		sink.write_u16(4)?;
+		sink.write_utf8("ping")?;
+		sink.flush()?;
+		sink.write_u8(0)?;
+		sink.flush()?;
+
+		let mut buf = [0; 16];
+		let len = receiver.recv(&mut buf)?;
+		assert_eq!(&buf[..len], b"\0\x04ping");
+		let len = receiver.recv(&mut buf)?;
+		assert_eq!(&buf[..len], b"\0");
+		Ok(())
+	}
+}
+
+mod interrupted {
+	use super::*;
+	use std::io::ErrorKind;
+	use data_streams::retry_interrupted;
+
+	/// Returns an interrupted error before each successful read.
+	struct Flaky<'a> {
+		data: &'a [u8],
+		interrupt: bool,
+	}
+
+	impl Read for Flaky<'_> {
+		fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+			self.interrupt = !self.interrupt;
+			if self.interrupt {
+				Err(ErrorKind::Interrupted.into())
+			} else {
+				self.data.read(buf)
+			}
+		}
+	}
+
+	fn flaky(data: &[u8]) -> Flaky<'_> {
+		Flaky { data, interrupt: false }
+	}
+
+	#[test]
+	fn buf_reader_retries() -> Result {
+		let mut source = BufReader::with_capacity(2, flaky(b"abcde"));
+		assert_eq!(source.fill_buffer()?, b"ab");
+		assert_eq!(source.read_exact_bytes(&mut [0; 3])?, b"abc");
+		assert_eq!(source.read_bytes(&mut [0; 4])?, b"de");
+		Ok(())
+	}
+
+	#[test]
+	fn take_retries() -> Result {
+		let mut source = Read::take(BufReader::with_capacity(4, flaky(b"abcde")), 3);
+		assert_eq!(source.fill_buffer()?, b"abc");
+		Ok(())
+	}
+
+	#[test]
+	fn retry_helper() -> Result {
+		let mut reader = flaky(b"abc");
+		let mut buf = [0; 4];
+		assert_eq!(retry_interrupted(|| reader.read(&mut buf))?, 3);
+		assert_eq!(
+			retry_interrupted(|| Err::<(), _>(ErrorKind::InvalidData.into())),
+			Err(Error::Io(ErrorKind::InvalidData.into()))
+		);
+		Ok(())
+	}
+}
@@ -8,7 +8,7 @@ use std::fmt::{Debug, Formatter};
 use std::str::from_utf8;
 use proptest::prelude::*;
 use proptest::string::bytes_regex;
-use data_streams::DataSource;
+use data_streams::{DataSink, DataSource};
 
 struct Utf8Deque {
 	deque: VecDeque<u8>,
@@ -112,4 +112,250 @@ proptest! {
 		let result = input.deque.clone().read_utf8(&mut buf);
 		prop_assert!(result.is_err());
 	}
+
+	#[test]
+	fn string_write_bytes(prefix in "(?s:.{0,8})", input in bytes_regex("(?s-u:.*)").unwrap()) {
+		let mut string = prefix.clone();
+		match string.write_bytes(&input) {
+			Ok(()) => prop_assert_eq!(&string.as_bytes()[prefix.len()..], &input),
+			Err(data_streams::Error::Utf8(error)) => {
+				let valid_up_to = error.valid_up_to();
+				prop_assert!(from_utf8(&input).is_err());
+				prop_assert_eq!(string.len(), prefix.len() + valid_up_to);
+				prop_assert!(string.starts_with(&prefix));
+				prop_assert_eq!(&string.as_bytes()[prefix.len()..], &input[..valid_up_to]);
+			}
+			Err(error) => return Err(TestCaseError::fail(format!("unexpected error: {error}")))
+		}
+	}
+}
+
+#[test]
+fn write_lossy() -> data_streams::Result {
+	const INPUT: &[u8] = b"hello \xF0\x90\x80world\xFF";
+	const LOSSY: &str = "hello \u{FFFD}world\u{FFFD}";
+
+	let mut string = String::new();
+	string.write_utf8_lossy(INPUT)?;
+	assert_eq!(string, LOSSY);
+
+	let mut vec = Vec::new();
+	vec.write_utf8_lossy(INPUT)?;
+	assert_eq!(vec, LOSSY.as_bytes());
+	Ok(())
+}
+
+#[test]
+fn string_write_iter_split_codepoint() -> data_streams::Result {
+	// The default chunk size would split the codepoint after the 63rd byte.
+	let input = "a".repeat(63) + "é";
+	let mut string = String::new();
+	string.write_iter(input.bytes())?;
+	assert_eq!(string, input);
+
+	match string.write_iter(b"ab\xFFc".iter().copied()) {
+		Err(data_streams::Error::Utf8(error)) => assert_eq!(error.valid_up_to(), 2),
+		result => panic!("expected a UTF-8 error, got {result:?}")
+	}
+	assert_eq!(string, input.clone() + "ab");
+
+	// Wrappers can't delegate write_iter, so this goes through the chunked default.
+	let mut string = String::new();
+	let mut sink = &mut string;
+	sink.write_iter(input.bytes())?;
+	match sink.write_iter(b"ab\xFFc".iter().copied()) {
+		Err(data_streams::Error::Utf8(error)) => assert_eq!(error.valid_up_to(), 2),
+		result => panic!("expected a UTF-8 error, got {result:?}")
+	}
+	assert_eq!(string, input + "ab");
+	Ok(())
+}
+
+#[test]
+fn borrowed_string_write_bytes() -> data_streams::Result {
+	fn write(mut sink: impl DataSink, buf: &[u8]) -> data_streams::Result {
+		sink.write_bytes(buf)
+	}
+
+	let mut string = String::from("x");
+	match write(&mut string, b"ab\xFFc") {
+		Err(data_streams::Error::Utf8(error)) => assert_eq!(error.valid_up_to(), 2),
+		result => panic!("expected a UTF-8 error, got {result:?}")
+	}
+	assert_eq!(string, "xab");
+
+	write(&mut string, "é".as_bytes())?;
+	assert_eq!(string, "xabé");
+	Ok(())
+}
+
+#[test]
+fn chars() {
+	let source = VecDeque::from("a🦀é".as_bytes().to_vec());
+	let chars: Vec<_> = source.chars().collect();
+	assert_eq!(chars, [Ok('a'), Ok('🦀'), Ok('é')]);
+}
+
+#[test]
+fn chars_errors() {
+	let mut chars = (&b"a\xFFb\xF0\x9F"[..]).chars();
+	assert_eq!(chars.next(), Some(Ok('a')));
+	assert!(matches!(chars.next(), Some(Err(data_streams::Error::Utf8(_)))));
+	assert_eq!(chars.next(), Some(Ok('b')));
+	assert_eq!(chars.next(), Some(Err(data_streams::Error::end(4, 1))));
+}
+
+#[test]
+fn read_utf8_while() -> data_streams::Result {
+	use data_streams::VecSource;
+
+	let mut source = VecDeque::from("héllo wörld".as_bytes().to_vec());
+	let mut buf = String::new();
+	assert_eq!(source.read_utf8_while(&mut buf, |c| c != 'ö')?, 8);
+	assert_eq!(buf, "héllo w");
+	assert_eq!(source, "örld".as_bytes());
+
+	let mut source = VecDeque::from(b"ab\xFFc".to_vec());
+	let mut buf = String::new();
+	match source.read_utf8_while(&mut buf, |_| true) {
+		Err(data_streams::Error::Utf8(error)) => assert_eq!(error.valid_up_to(), 2),
+		result => panic!("expected a UTF-8 error, got {result:?}")
+	}
+	assert_eq!(buf, "ab");
+	assert_eq!(source, b"\xFFc");
+	Ok(())
+}
+
+#[test]
+fn str_source_read_utf8() -> data_streams::Result {
+	use data_streams::StrSource;
+
+	let mut source = StrSource::new("héllo");
+	assert_eq!(source.read_utf8(&mut [0; 1])?, "h");
+	// Stops between the bytes of 'é', only the valid prefix is consumed.
+	match source.read_utf8(&mut [0; 1]) {
+		Err(data_streams::Error::Utf8(error)) => assert_eq!(error.valid_up_to(), 0),
+		result => panic!("expected a UTF-8 error, got {result:?}")
+	}
+	assert_eq!(source.read_utf8(&mut [0; 16])?, "éllo");
+	Ok(())
+}
+
+#[test]
+fn read_utf8_into() -> data_streams::Result {
+	let mut source = &b"ab\xFFc"[..];
+	let mut buf = String::from("x");
+	match source.read_utf8_into(4, &mut buf) {
+		Err(data_streams::Error::Utf8(error)) => assert_eq!(error.valid_up_to(), 2),
+		result => panic!("expected a UTF-8 error, got {result:?}")
+	}
+	assert_eq!(buf, "xab");
+	assert_eq!(source, b"\xFFc");
+
+	let mut source = "héllo".as_bytes();
+	assert_eq!(source.read_utf8_into(16, &mut buf)?, "héllo");
+	assert_eq!(buf, "xabhéllo");
+	Ok(())
+}
+
+#[test]
+fn read_utf8_to_end_skip_bom() -> data_streams::Result {
+	use data_streams::VecSource;
+
+	let mut source = VecDeque::from(*b"\xEF\xBB\xBFh\xC3\xA9llo");
+	let mut buf = String::new();
+	assert_eq!(source.read_utf8_to_end_skip_bom(&mut buf)?, "héllo");
+
+	let mut source = VecDeque::from(*b"h\xC3\xA9llo");
+	let mut buf = String::new();
+	assert_eq!(source.read_utf8_to_end_skip_bom(&mut buf)?, "héllo");
+	Ok(())
+}
+
+#[test]
+#[cfg(feature = "unstable_specialization")]
+fn read_utf8_to_end_skip_split_bom() -> data_streams::Result {
+	use std::io::{BufReader, Read};
+	use data_streams::VecSource;
+
+	// The first read ends partway through the byte order mark.
+	let mut source = BufReader::new((&b"\xEF\xBB"[..]).chain(&b"\xBFh\xC3\xA9llo"[..]));
+	let mut buf = String::from("x");
+	assert_eq!(source.read_utf8_to_end_skip_bom(&mut buf)?, "héllo");
+	assert_eq!(buf, "xhéllo");
+
+	// Split bytes starting like a mark, but forming another codepoint.
+	let mut source = BufReader::new((&b"\xEF\xBB"[..]).chain(&b"\x80h"[..]));
+	let mut buf = String::new();
+	assert_eq!(source.read_utf8_to_end_skip_bom(&mut buf)?, "\u{FEC0}h");
+	Ok(())
+}
+
+#[test]
+fn read_line_lossy_buffered() -> data_streams::Result {
+	use std::io::BufReader;
+
+	// The invalid byte and the crab are split across buffer fills.
+	let bytes = b"one\n\ntw\xFFo \xF0\x9F\xA6\x80\nend";
+	let mut source = BufReader::with_capacity(3, &bytes[..]);
+	let mut lines = Vec::new();
+	loop {
+		let mut line = String::new();
+		match source.read_line_lossy(&mut line)? {
+			0 => break,
+			_ => lines.push(line)
+		}
+	}
+	assert_eq!(lines, ["one\n", "\n", "tw\u{FFFD}o 🦀\n", "end"]);
+
+	let mut source = VecDeque::from(bytes.to_vec());
+	let mut buf = String::new();
+	assert_eq!(source.read_line_lossy(&mut buf)?, 4);
+	assert_eq!(Box::new(&mut source).read_line_lossy(&mut buf)?, 1);
+	assert_eq!(buf, "one\n\n");
+	Ok(())
+}
+
+#[test]
+fn repeat_read_utf8_lead_byte() {
+	use std::io::repeat;
+	use data_streams::Error;
+
+	let error = |buf: &mut [u8]| match repeat(0xC3).read_utf8(buf) {
+		Err(Error::Utf8(error)) => (error.valid_up_to(), error.error_len()),
+		result => panic!("expected a UTF-8 error, got {result:?}")
+	};
+	// Each lead byte is followed by another lead byte, not a continuation byte.
+	assert_eq!(error(&mut [0; 8]), (0, Some(1)));
+	// A single lead byte is incomplete.
+	assert_eq!(error(&mut [0; 1]), (0, None));
+	assert_eq!(repeat(b'a').read_utf8(&mut [0; 4]), Ok("aaaa"));
+}
+
+#[test]
+fn read_utf8_counted_chunks() -> data_streams::Result {
+	let text = "Grüße, 世界! 🦀";
+	let mut source = text.as_bytes();
+	let mut buf = [0; 4];
+	let mut result = String::new();
+	while !source.is_empty() {
+		let (str, count) = source.read_utf8_counted(&mut buf)?;
+		let valid = str.len();
+		result.push_str(str);
+
+		// Complete the codepoint left after the string.
+		let tail_len = count - valid;
+		if tail_len > 0 {
+			let width = buf[valid].leading_ones() as usize;
+			let mut char_buf = [0; 4];
+			char_buf[..tail_len].copy_from_slice(&buf[valid..count]);
+			source.read_exact_bytes(&mut char_buf[tail_len..width])?;
+			result.push_str(from_utf8(&char_buf[..width]).unwrap());
+		}
+	}
+	assert_eq!(result, text);
+
+	let mut source = &b"ab\xFFc"[..];
+	assert!(matches!(source.read_utf8_counted(&mut [0; 4]), Err(data_streams::Error::Utf8(_))));
+	Ok(())
 }
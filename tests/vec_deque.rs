@@ -0,0 +1,112 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "alloc")]
+
+use std::collections::VecDeque;
+use data_streams::{BufferAccess, DataSource, Error, Result, VecSource};
+
+#[test]
+fn buffer_contiguous() {
+	// Pushing to the front wraps around to the end of the deque's memory.
+	let mut source = VecDeque::from(b"defg".to_vec());
+	for &byte in b"abc".iter().rev() {
+		source.push_front(byte);
+	}
+	assert!(source.buffer().len() < source.len());
+	assert_eq!(source.buffer_contiguous(), b"abcdefg");
+	assert_eq!(source.buffer(), b"abcdefg");
+}
+
+#[test]
+fn buffer_count_wrapped() {
+	let mut source = VecDeque::from(b"cd".to_vec());
+	source.push_front(b'b');
+	source.push_front(b'a');
+	assert!(source.buffer().len() < source.len());
+	assert_eq!(source.buffer_count(), 4);
+	assert_eq!(source.available(), 4);
+	// Wrappers must delegate the count rather than use the slice length.
+	let wrapped = &mut source;
+	assert_eq!(wrapped.buffer_count(), 4);
+}
+
+#[test]
+fn read_into_vec_end() -> Result {
+	let mut source = VecDeque::from(b"abc".to_vec());
+	let mut buf = b"xy".to_vec();
+	assert_eq!(source.read_into_vec(&mut buf, 8)?, 3);
+	assert_eq!(buf, b"xyabc");
+	assert!(buf.capacity() >= 10);
+	assert_eq!(source.read_into_vec(&mut buf, 8)?, 0);
+	assert_eq!(buf, b"xyabc");
+	Ok(())
+}
+
+#[test]
+fn peek_data_wrapped() -> Result {
+	let mut source = VecDeque::from(b"cd".to_vec());
+	source.push_front(b'b');
+	source.push_front(b'a');
+	assert_eq!(source.peek_data::<[u8; 4]>()?, *b"abcd");
+	assert_eq!(source.len(), 4);
+	Ok(())
+}
+
+#[test]
+fn read_array_ref_wrapped() -> Result {
+	let mut source = VecDeque::from(b"CDE".to_vec());
+	for &byte in b"\0AB".iter().rev() {
+		source.push_front(byte);
+	}
+	assert!(source.buffer().len() < 4);
+	assert_eq!(source.read_array_ref::<4>()?, b"\0ABC");
+	source.drain_buffer(4);
+	assert_eq!(source, b"DE");
+	Ok(())
+}
+
+#[test]
+fn fill_exact_wrapped() -> Result {
+	let mut source = VecDeque::from(b"CDE".to_vec());
+	for &byte in b"\0AB".iter().rev() {
+		source.push_front(byte);
+	}
+	assert!(source.buffer().len() < 5);
+	assert_eq!(source.fill_exact(5)?, b"\0ABCDE");
+	assert_eq!(source.fill_exact(7), Err(Error::end(7, 6)));
+	Ok(())
+}
+
+#[test]
+fn read_bytes_wrapped() -> Result {
+	let mut source = VecDeque::from(b"defg".to_vec());
+	for &byte in b"abc".iter().rev() {
+		source.push_front(byte);
+	}
+	assert!(source.as_slices().0.len() < source.len());
+	assert_eq!(source.read_bytes(&mut [0; 2])?, b"ab");
+	assert_eq!(source.read_bytes(&mut [0; 3])?, b"cde");
+	assert_eq!(source.read_bytes(&mut [0; 8])?, b"fg");
+	assert!(source.is_empty());
+	Ok(())
+}
+
+#[test]
+fn boxed_source() -> Result {
+	let mut source = Box::new(VecDeque::from(b"Hi, World!".to_vec()));
+	#[cfg(feature = "utf8")]
+	assert_eq!(source.read_utf8(&mut [0; 2])?, "Hi");
+	#[cfg(not(feature = "utf8"))]
+	assert_eq!(source.read_bytes(&mut [0; 2])?, b"Hi");
+	// Requires the wrapper itself to implement VecSource, not just dereference to it.
+	fn read_all(source: &mut impl VecSource, buf: &mut Vec<u8>) -> Result<usize> {
+		source.read_to_end(buf).map(<[u8]>::len)
+	}
+
+	let mut buf = Vec::new();
+	assert_eq!(read_all(&mut source, &mut buf)?, 8);
+	assert_eq!(buf, b", World!");
+	assert!(source.is_empty());
+	Ok(())
+}
@@ -0,0 +1,89 @@
+// Copyright 2025 - Strixpyrr
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "digest")]
+
+use data_streams::{DataSource, Error, Result, Verified};
+use proptest::arbitrary::any;
+use proptest::collection::vec;
+use proptest::{prop_assert_eq, proptest};
+use sha2::{Digest, Sha256};
+
+fn with_digest(data: &[u8]) -> Vec<u8> {
+	let mut bytes = data.to_vec();
+	bytes.extend_from_slice(&Sha256::digest(data));
+	bytes
+}
+
+proptest! {
+	#[test]
+	fn read_chunked(data in vec(any::<u8>(), 0..=256), chunk in 1usize..=48) {
+		let bytes = with_digest(&data);
+		let mut source = Verified::<_, Sha256>::new(&bytes[..]);
+		let mut read = Vec::new();
+		let mut buf = vec![0; chunk];
+		loop {
+			let bytes = source.read_bytes(&mut buf).unwrap();
+			if bytes.is_empty() {
+				break
+			}
+			read.extend_from_slice(bytes);
+		}
+		prop_assert_eq!(read, data);
+	}
+}
+
+#[test]
+fn mismatch() {
+	let mut bytes = with_digest(b"Hello, World!");
+	bytes[0] = b'J';
+	let mut source = Verified::<_, Sha256>::new(&bytes[..]);
+	assert_eq!(source.read_bytes(&mut [0; 64]), Err(Error::ChecksumMismatch));
+}
+
+#[test]
+fn truncated_digest() {
+	let bytes = &with_digest(b"Hello")[..20];
+	let mut source = Verified::<_, Sha256>::new(bytes);
+	assert_eq!(source.read_bytes(&mut [0; 64]), Err(Error::end(32, 20)));
+}
+
+#[test]
+fn finish() -> Result {
+	let mut bytes = with_digest(b"Hello, World!");
+	bytes.extend_from_slice(b"trailing data");
+	let mut source = Verified::<_, Sha256>::new(&bytes[..]);
+	source.read_exact_bytes(&mut [0; 13])?;
+	source.finish()
+}
+
+/// Reads at most two bytes at a time.
+struct Trickle<'a>(&'a [u8]);
+
+impl DataSource for Trickle<'_> {
+	fn available(&self) -> usize { 0 }
+	fn request(&mut self, _: usize) -> Result<bool> { Ok(false) }
+	fn skip(&mut self, count: usize) -> Result<usize> {
+		let count = count.min(2).min(self.0.len());
+		self.0 = &self.0[count..];
+		Ok(count)
+	}
+	fn read_bytes<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		let len = buf.len().min(2);
+		self.0.read_bytes(&mut buf[..len])
+	}
+}
+
+#[test]
+fn finish_non_greedy() -> Result {
+	let mut bytes = with_digest(b"Hello, World!");
+	bytes.extend_from_slice(b"trailing data");
+	let mut source = Verified::<_, Sha256>::new(Trickle(&bytes));
+	assert_eq!(source.read_bytes(&mut [0; 13])?, b"Hello, World!");
+	source.finish()?;
+
+	let bytes = &with_digest(b"Hello")[..20];
+	let source = Verified::<_, Sha256>::new(Trickle(bytes));
+	assert_eq!(source.finish(), Err(Error::end(32, 20)));
+	Ok(())
+}